@@ -32,4 +32,5 @@ mod types;
 pub(crate) mod tests;
 pub(crate) use root::{Library, LockedUnit};
 
-pub use self::root::{DesignRoot, EntHierarchy};
+pub use self::names::ResolvedName;
+pub use self::root::{DesignRoot, EntHierarchy, SymbolUnderCursor};
@@ -136,6 +136,7 @@ pub(super) struct AnalyzeContext<'a> {
 
     pub work_sym: Symbol,
     std_sym: Symbol,
+    ieee_sym: Symbol,
     standard_sym: Symbol,
     pub(super) is_std_logic_1164: bool,
 
@@ -170,6 +171,7 @@ impl<'a> AnalyzeContext<'a> {
         AnalyzeContext {
             work_sym: root.symbol_utf8("work"),
             std_sym: root.symbol_utf8("std"),
+            ieee_sym: root.symbol_utf8("ieee"),
             standard_sym: root.symbol_utf8("standard"),
             is_std_logic_1164: current_unit
                 == &UnitId::package(
@@ -290,6 +292,38 @@ impl<'a> AnalyzeContext<'a> {
             scope.make_all_potentially_visible(None, standard_region);
         }
 
+        self.add_predefined_packages(scope)?;
+
+        Ok(())
+    }
+
+    /// Make the declarations of packages registered as predefined visible
+    fn add_predefined_packages(&self, scope: &Scope<'a>) -> FatalResult {
+        for (library_name, package_name) in self.root.predefined_packages.iter() {
+            let work_library_name = self.work_library_name();
+            if work_library_name == library_name
+                || work_library_name == &self.std_sym
+                || work_library_name == &self.ieee_sym
+            {
+                continue;
+            }
+
+            let Some(unit) = self.get_primary_unit(library_name, package_name) else {
+                continue;
+            };
+
+            let data = self.get_analysis(None, unit)?;
+            if let AnyDesignUnit::Primary(primary) = data.deref() {
+                if let Some(id) = primary.ent_id() {
+                    if let AnyEntKind::Design(Design::Package(_, region)) =
+                        self.arena.get(id).kind()
+                    {
+                        scope.make_all_potentially_visible(None, region);
+                    }
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -312,7 +346,7 @@ impl<'a> AnalyzeContext<'a> {
         self.get_package_body().is_some()
     }
 
-    fn get_analysis(
+    pub(super) fn get_analysis(
         &self,
         use_pos: Option<&SrcPos>,
         unit: &'a LockedUnit,
@@ -341,7 +375,7 @@ impl<'a> AnalyzeContext<'a> {
         None
     }
 
-    fn get_secondary_unit(
+    pub(super) fn get_secondary_unit(
         &self,
         library_name: &Symbol,
         primary: &Symbol,
@@ -26,6 +26,7 @@ impl<'a> AnalyzeContext<'a> {
         match rhs {
             AssignmentRightHand::Simple(expr) => {
                 self.analyze_expression_for_target(scope, ttyp, expr, diagnostics)?;
+                self.check_target_length(scope, target, expr, diagnostics)?;
             }
             AssignmentRightHand::Conditional(conditionals) => {
                 let Conditionals {
@@ -61,13 +62,26 @@ impl<'a> AnalyzeContext<'a> {
         scope: &Scope<'a>,
         target: &mut WithPos<Target>,
         assignment_type: AssignmentType,
+        delay_mechanism: &mut Option<DelayMechanism>,
         rhs: &mut AssignmentRightHand<Waveform>,
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> FatalResult {
         let ttyp = as_fatal(self.resolve_target(scope, target, assignment_type, diagnostics))?;
+        if let Some(DelayMechanism::Inertial {
+            reject: Some(ref mut reject),
+        }) = delay_mechanism
+        {
+            self.expr_with_ttyp(scope, self.time(), reject, diagnostics)?;
+            check_reject_time(reject, rhs, diagnostics);
+        }
         match rhs {
             AssignmentRightHand::Simple(wavf) => {
                 self.analyze_waveform(scope, ttyp, wavf, diagnostics)?;
+                if let Waveform::Elements(ref mut elems) = wavf {
+                    if let [elem] = elems.as_mut_slice() {
+                        self.check_target_length(scope, target, &mut elem.value, diagnostics)?;
+                    }
+                }
             }
             AssignmentRightHand::Conditional(conditionals) => {
                 let Conditionals {
@@ -135,3 +149,76 @@ impl<'a> AnalyzeContext<'a> {
         Ok(())
     }
 }
+
+/// The pulse rejection limit shall not exceed the time of the first waveform element
+fn check_reject_time(
+    reject: &WithPos<Expression>,
+    rhs: &AssignmentRightHand<Waveform>,
+    diagnostics: &mut dyn DiagnosticHandler,
+) {
+    let Some(reject_time) = static_time(&reject.item) else {
+        return;
+    };
+
+    let waveforms: Vec<&Waveform> = match rhs {
+        AssignmentRightHand::Simple(wavf) => vec![wavf],
+        AssignmentRightHand::Conditional(conditionals) => conditionals
+            .conditionals
+            .iter()
+            .map(|conditional| &conditional.item)
+            .chain(conditionals.else_item.iter())
+            .collect(),
+        AssignmentRightHand::Selected(selection) => selection
+            .alternatives
+            .iter()
+            .map(|alternative| &alternative.item)
+            .collect(),
+    };
+
+    for wavf in waveforms {
+        let Waveform::Elements(elems) = wavf else {
+            continue;
+        };
+        let Some(first) = elems.first() else {
+            continue;
+        };
+        let after_time = match first.after {
+            Some(ref after) => static_time(&after.item),
+            None => Some(0.0),
+        };
+
+        if matches!(after_time, Some(after_time) if reject_time > after_time) {
+            diagnostics.warning(
+                &reject.pos,
+                "Reject time is greater than the time of the first waveform element",
+            );
+            return;
+        }
+    }
+}
+
+/// The value in femtoseconds of a time literal using the units of std.standard.time
+fn static_time(expr: &Expression) -> Option<f64> {
+    let Expression::Literal(Literal::Physical(PhysicalLiteral { value, unit })) = expr else {
+        return None;
+    };
+
+    let scale = match unit.item.item.name_utf8().to_lowercase().as_str() {
+        "fs" => 1e0,
+        "ps" => 1e3,
+        "ns" => 1e6,
+        "us" => 1e9,
+        "ms" => 1e12,
+        "sec" => 1e15,
+        "min" => 60e15,
+        "hr" => 3600e15,
+        _ => return None,
+    };
+
+    let value = match value {
+        AbstractLiteral::Integer(value) => *value as f64,
+        AbstractLiteral::Real(value) => *value,
+    };
+
+    Some(value * scale)
+}
@@ -448,13 +448,19 @@ impl<'a> AnalyzeContext<'a> {
 
             let resolved_formals = resolved_pairs
                 .into_iter()
-                .map(|(_, resolved_formal)| resolved_formal)
+                .map(|(formal_pos, resolved_formal)| (formal_pos.clone(), resolved_formal))
                 .collect_vec();
 
-            for (resolved_formal, actual) in resolved_formals
+            for ((formal_pos, resolved_formal), actual) in resolved_formals
                 .iter()
                 .zip(elems.iter_mut().map(|assoc| &mut assoc.actual))
             {
+                if let Some(resolved_formal) = resolved_formal {
+                    if formal_region.typ == InterfaceType::Port && resolved_formal.is_converted {
+                        self.check_formal_conversion_mode(resolved_formal, formal_pos, diagnostics);
+                    }
+                }
+
                 match &mut actual.item {
                     ActualPart::Expression(expr) => {
                         if let Some(resolved_formal) = resolved_formal {
@@ -474,6 +480,14 @@ impl<'a> AnalyzeContext<'a> {
                                 expr,
                                 diagnostics,
                             )?;
+                            if formal_region.typ == InterfaceType::Port {
+                                self.check_actual_conversion_mode(
+                                    resolved_formal,
+                                    expr,
+                                    &actual.pos,
+                                    diagnostics,
+                                );
+                            }
                         } else {
                             self.expr_pos_unknown_ttyp(scope, &actual.pos, expr, diagnostics)?;
                         }
@@ -485,6 +499,64 @@ impl<'a> AnalyzeContext<'a> {
         Ok(())
     }
 
+    // LRM 6.5.7.1: A formal conversion may only be used when the mode of the formal
+    // is out, inout, buffer or linkage since the value flows from the formal to the actual
+    fn check_formal_conversion_mode(
+        &self,
+        resolved_formal: &ResolvedFormal<'a>,
+        formal_pos: &SrcPos,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        if resolved_formal.iface.mode() == Some(Mode::In) {
+            diagnostics.error(
+                formal_pos,
+                format!(
+                    "Formal conversion is not allowed for {}",
+                    resolved_formal.iface.describe()
+                ),
+            );
+        }
+    }
+
+    // LRM 6.5.7.1: An actual conversion may only be used when the mode of the formal
+    // is in, inout or linkage since the value flows from the actual to the formal
+    fn check_actual_conversion_mode(
+        &self,
+        resolved_formal: &ResolvedFormal<'a>,
+        expr: &Expression,
+        actual_pos: &SrcPos,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        if !matches!(
+            resolved_formal.iface.mode(),
+            Some(Mode::Out) | Some(Mode::Buffer)
+        ) {
+            return;
+        }
+
+        if let Expression::Name(name) = expr {
+            if let Name::CallOrIndexed(fcall) = name.as_ref() {
+                let is_function_call = fcall
+                    .name
+                    .item
+                    .get_suffix_reference()
+                    .and_then(|id| OverloadedEnt::from_any(self.arena.get(id)))
+                    .map(|ent| ent.is_function())
+                    .unwrap_or(false);
+
+                if is_function_call {
+                    diagnostics.error(
+                        actual_pos,
+                        format!(
+                            "Actual conversion is not allowed for {}",
+                            resolved_formal.iface.describe()
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
     // LRM 4.2.2.1: In a subprogram, the interface mode must match the mode of the actual designator
     // when the interface mode is signal, variable or file. Furthermore, they must be a single name.
     fn check_parameter_interface(
@@ -77,21 +77,35 @@ impl<'a> AnalyzeContext<'a> {
     ) -> FatalResult {
         match statement.statement.item {
             ConcurrentStatement::Block(ref mut block) => {
+                let nested = scope.nested();
                 if let Some(ref mut guard_condition) = block.guard_condition {
                     self.boolean_expr(scope, guard_condition, diagnostics)?;
+                    // A guarded block implicitly declares the boolean signal GUARD
+                    let guard = self.arena.explicit(
+                        self.root.symbol_utf8("GUARD"),
+                        parent,
+                        AnyEntKind::Object(Object {
+                            class: ObjectClass::Signal,
+                            iface: None,
+                            subtype: Subtype::new(self.boolean()),
+                            has_default: true,
+                        }),
+                        Some(&guard_condition.pos),
+                        None,
+                    );
+                    nested.add(guard, diagnostics);
                 }
-                let nested = scope.nested();
                 if let Some(ref mut list) = block.header.generic_clause {
                     self.analyze_interface_list(&nested, parent, list, diagnostics)?;
                 }
                 if let Some(ref mut list) = block.header.generic_map {
-                    self.analyze_assoc_elems(scope, &mut list.list.items[..], diagnostics)?;
+                    self.analyze_assoc_elems(scope, list.list.as_mut_slice(), diagnostics)?;
                 }
                 if let Some(ref mut list) = block.header.port_clause {
                     self.analyze_interface_list(&nested, parent, list, diagnostics)?;
                 }
                 if let Some(ref mut list) = block.header.port_map {
-                    self.analyze_assoc_elems(scope, &mut list.list.items[..], diagnostics)?;
+                    self.analyze_assoc_elems(scope, list.list.as_mut_slice(), diagnostics)?;
                 }
 
                 self.define_labels_for_concurrent_part(
@@ -183,12 +197,17 @@ impl<'a> AnalyzeContext<'a> {
                 self.analyze_instance(scope, instance, diagnostics)?;
             }
             ConcurrentStatement::Assignment(ref mut assign) => {
-                // @TODO more delaymechanism
-                let ConcurrentSignalAssignment { target, rhs, .. } = assign;
+                let ConcurrentSignalAssignment {
+                    target,
+                    delay_mechanism,
+                    rhs,
+                    ..
+                } = assign;
                 self.analyze_waveform_assignment(
                     scope,
                     target,
                     AssignmentType::Signal,
+                    delay_mechanism,
                     rhs,
                     diagnostics,
                 )?;
@@ -196,6 +215,7 @@ impl<'a> AnalyzeContext<'a> {
             ConcurrentStatement::ProcedureCall(ref mut pcall) => {
                 let ConcurrentProcedureCall { call, .. } = pcall;
                 self.analyze_procedure_call(scope, call, diagnostics)?;
+                self.check_concurrent_call_actuals(scope, call, diagnostics)?;
             }
             ConcurrentStatement::Assert(ref mut assert) => {
                 let ConcurrentAssertStatement {
@@ -301,7 +321,7 @@ impl<'a> AnalyzeContext<'a> {
                                 instance
                                     .generic_map
                                     .as_mut()
-                                    .map(|it| it.list.items.as_mut_slice())
+                                    .map(|it| it.list.as_mut_slice())
                                     .unwrap_or(&mut []),
                                 diagnostics,
                             )?;
@@ -312,7 +332,7 @@ impl<'a> AnalyzeContext<'a> {
                                 instance
                                     .port_map
                                     .as_mut()
-                                    .map(|it| it.list.items.as_mut_slice())
+                                    .map(|it| it.list.as_mut_slice())
                                     .unwrap_or(&mut []),
                                 diagnostics,
                             )?;
@@ -359,7 +379,7 @@ impl<'a> AnalyzeContext<'a> {
                         instance
                             .generic_map
                             .as_mut()
-                            .map(|it| it.list.items.as_mut_slice())
+                            .map(|it| it.list.as_mut_slice())
                             .unwrap_or(&mut []),
                         diagnostics,
                     )?;
@@ -370,7 +390,7 @@ impl<'a> AnalyzeContext<'a> {
                         instance
                             .port_map
                             .as_mut()
-                            .map(|it| it.list.items.as_mut_slice())
+                            .map(|it| it.list.as_mut_slice())
                             .unwrap_or(&mut []),
                         diagnostics,
                     )?;
@@ -414,7 +434,65 @@ impl<'a> AnalyzeContext<'a> {
         let Some(aspect) = map else {
             return Ok(());
         };
-        self.analyze_assoc_elems(scope, aspect.list.items.as_mut_slice(), diagnostics)
+        self.analyze_assoc_elems(scope, aspect.list.as_mut_slice(), diagnostics)
+    }
+
+    // The equivalent process of a concurrent procedure call has no variables of its own,
+    // so a variable cannot be associated with an out or inout variable parameter
+    fn check_concurrent_call_actuals(
+        &self,
+        scope: &Scope<'a>,
+        call: &mut WithPos<CallOrIndexed>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let CallOrIndexed { name, parameters } = &mut call.item;
+        let Some(procedure) = name
+            .item
+            .get_suffix_reference()
+            .and_then(|id| OverloadedEnt::from_any(self.arena.get(id)))
+        else {
+            return Ok(());
+        };
+
+        for (idx, AssociationElement { formal, actual }) in parameters.iter_mut().enumerate() {
+            let iface = match formal {
+                Some(formal) => formal
+                    .item
+                    .get_suffix_reference()
+                    .and_then(|id| InterfaceEnt::from_any(self.arena.get(id))),
+                None => procedure.formals().nth(idx),
+            };
+            let Some(iface) = iface else {
+                continue;
+            };
+            if iface.interface_class() != InterfaceClass::Variable
+                || !matches!(iface.mode(), Some(Mode::Out) | Some(Mode::InOut))
+            {
+                continue;
+            }
+
+            let ActualPart::Expression(Expression::Name(ref mut actual_name)) = actual.item else {
+                continue;
+            };
+            if let Some(ResolvedName::ObjectName(oname)) =
+                as_fatal(self.name_resolve(scope, &actual.pos, actual_name, &mut NullDiagnostics))?
+            {
+                if matches!(
+                    oname.base.class(),
+                    ObjectClass::Variable | ObjectClass::SharedVariable
+                ) {
+                    diagnostics.error(
+                        &actual.pos,
+                        format!(
+                            "{} cannot be associated with {} in a concurrent procedure call",
+                            oname.base.describe(),
+                            iface.describe()
+                        ),
+                    );
+                }
+            }
+        }
+        Ok(())
     }
 
     pub fn sensitivity_list_check(
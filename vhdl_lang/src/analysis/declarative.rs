@@ -6,12 +6,13 @@
 
 use super::names::*;
 use super::*;
+use crate::ast::search::*;
 use crate::ast::*;
 use crate::data::*;
 use crate::named_entity::{Signature, *};
-use crate::{ast, named_entity, HasTokenSpan};
+use crate::{ast, named_entity, HasTokenSpan, TokenAccess};
 use analyze::*;
-use fnv::FnvHashMap;
+use fnv::{FnvHashMap, FnvHashSet};
 use std::collections::hash_map::Entry;
 
 impl Declaration {
@@ -36,6 +37,7 @@ impl Declaration {
                     | Use(_)
                     | Package(_)
                     | Configuration(_)
+                    | Disconnection(_)
             ),
             AnyEntKind::Design(Design::Configuration) => {
                 matches!(self, Use(_) | Attribute(ast::Attribute::Specification(_)))
@@ -52,6 +54,7 @@ impl Declaration {
                     | SubprogramBody(_)
                     | Use(_)
                     | Package(_)
+                    | Disconnection(_)
             ),
             AnyEntKind::Design(Design::PackageBody | Design::UninstPackage(..))
             | AnyEntKind::Overloaded(
@@ -78,8 +81,10 @@ impl Declaration {
             ),
             AnyEntKind::Design(Design::Package(..)) => matches!(
                 self,
-                Object(_)
-                    | File(_)
+                Object(ObjectDeclaration {
+                    class: Constant | Signal | SharedVariable,
+                    ..
+                }) | File(_)
                     | Type(_)
                     | Component(_)
                     | Attribute(_)
@@ -88,6 +93,7 @@ impl Declaration {
                     | SubprogramInstantiation(_)
                     | Use(_)
                     | Package(_)
+                    | Disconnection(_)
             ),
             _ => {
                 // AnyEntKind::Library is used in tests for a generic declarative region
@@ -201,9 +207,113 @@ impl<'a> AnalyzeContext<'a> {
                 }
             }
         }
+
+        self.check_constant_elaboration_cycles(declarations, diagnostics);
         Ok(())
     }
 
+    /// A constant cannot be elaborated when its initial value calls a function
+    /// of the same declarative part that in turn reads the constant
+    fn check_constant_elaboration_cycles(
+        &self,
+        declarations: &[Declaration],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        // The subprogram bodies of the declarative part and the entities that each body references
+        let mut bodies: Vec<(EntRef<'a>, FnvHashSet<EntityId>)> = Vec::new();
+        // The subprogram body of both the body and the subprogram declaration
+        let mut body_of: FnvHashMap<EntityId, usize> = FnvHashMap::default();
+
+        for decl in declarations.iter() {
+            let Declaration::SubprogramBody(body) = decl else {
+                continue;
+            };
+            let id = match body.specification {
+                SubprogramSpecification::Procedure(ref proc) => proc.designator.decl.get(),
+                SubprogramSpecification::Function(ref func) => func.designator.decl.get(),
+            };
+            let Some(id) = id else {
+                continue;
+            };
+
+            let ent = self.arena.get(id);
+            body_of.insert(ent.id(), bodies.len());
+            if let Related::DeclaredBy(decl_ent) = ent.related {
+                body_of.insert(decl_ent.id(), bodies.len());
+            }
+
+            let mut references = ReferenceCollector::default();
+            let _ = decl.search(self.ctx, &mut references);
+            bodies.push((ent, references.ids));
+        }
+
+        if bodies.is_empty() {
+            return;
+        }
+
+        for decl in declarations.iter() {
+            let Declaration::Object(ObjectDeclaration {
+                class: ObjectClass::Constant,
+                ident,
+                expression: Some(expr),
+                ..
+            }) = decl
+            else {
+                continue;
+            };
+            let Some(constant_id) = ident.decl.get() else {
+                continue;
+            };
+
+            let mut called = ReferenceCollector::default();
+            let _ = expr.search(self.ctx, &mut called);
+
+            for (id, _) in called.references {
+                let Some(&idx) = body_of.get(&id) else {
+                    continue;
+                };
+                let function = bodies[idx].0;
+
+                // Follow the calls from the function body until the constant is found
+                let mut visited = FnvHashSet::default();
+                visited.insert(idx);
+                let mut pending = vec![idx];
+                let mut is_cyclic = false;
+
+                while let Some(idx) = pending.pop() {
+                    let references = &bodies[idx].1;
+                    if references.contains(&constant_id) {
+                        is_cyclic = true;
+                        break;
+                    }
+                    for id in references.iter() {
+                        if let Some(&called_idx) = body_of.get(id) {
+                            if visited.insert(called_idx) {
+                                pending.push(called_idx);
+                            }
+                        }
+                    }
+                }
+
+                if is_cyclic {
+                    let mut error = Diagnostic::error(
+                        ident.pos(),
+                        format!(
+                            "Constant '{}' depends on its own value through a call to '{}'",
+                            ident.name(),
+                            function.designator()
+                        ),
+                    );
+                    if let Some(decl_pos) = function.decl_pos() {
+                        error.add_related(decl_pos, "Defined here");
+                    }
+                    diagnostics.push(error);
+                    break;
+                }
+            }
+        }
+    }
+
     fn analyze_alias_declaration(
         &self,
         scope: &Scope<'a>,
@@ -350,6 +460,9 @@ impl<'a> AnalyzeContext<'a> {
 
                 if let Some(ref mut expr) = object_decl.expression {
                     if let Ok(ref subtype) = subtype {
+                        if object_decl.subtype_indication.type_mark.item.attr.is_none() {
+                            self.check_aggregate_index_range(scope, *subtype, expr, diagnostics)?;
+                        }
                         self.expr_pos_with_ttyp(
                             scope,
                             subtype.type_mark(),
@@ -363,6 +476,8 @@ impl<'a> AnalyzeContext<'a> {
                 }
 
                 if let Some(subtype) = as_fatal(subtype)? {
+                    self.check_object_subtype_is_constrained(object_decl, subtype, diagnostics);
+
                     let kind = if object_decl.class == ObjectClass::Constant
                         && object_decl.expression.is_none()
                     {
@@ -417,13 +532,20 @@ impl<'a> AnalyzeContext<'a> {
                 ))?;
 
                 if let Some(ref mut expr) = open_info {
-                    self.expr_unknown_ttyp(scope, expr, diagnostics)?;
+                    self.expr_with_ttyp(scope, self.file_open_kind(), expr, diagnostics)?;
                 }
                 if let Some(ref mut expr) = file_name {
-                    self.expr_unknown_ttyp(scope, expr, diagnostics)?;
+                    self.expr_with_ttyp(scope, self.string(), expr, diagnostics)?;
                 }
 
                 if let Some(subtype) = subtype {
+                    if !matches!(subtype.type_mark().kind(), Type::File) {
+                        diagnostics.error(
+                            &subtype_indication.type_mark.pos,
+                            format!("{} is not a file type", subtype.type_mark().describe()),
+                        );
+                    }
+
                     scope.add(
                         self.arena
                             .define(ident, parent, AnyEntKind::File(subtype), Some(src_span)),
@@ -582,13 +704,50 @@ impl<'a> AnalyzeContext<'a> {
                     scope.add(ent, diagnostics);
                 }
             }
-            Declaration::Configuration(..) => {}
+            Declaration::Configuration(ref mut config) => {
+                self.configuration_specification(scope, config, diagnostics)?;
+            }
+            Declaration::Disconnection(ref mut disconnection) => {
+                self.disconnection_specification(scope, disconnection, diagnostics)?;
+            }
             Declaration::Type(..) => unreachable!("Handled elsewhere"),
         };
 
         Ok(())
     }
 
+    /// Signals and variables must have a constrained subtype while constants
+    /// may get their constraint from the initial value or full declaration
+    fn check_object_subtype_is_constrained(
+        &self,
+        object_decl: &ObjectDeclaration,
+        subtype: Subtype<'a>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        if object_decl.class == ObjectClass::Constant {
+            return;
+        }
+
+        let subtype_indication = &object_decl.subtype_indication;
+        if subtype_indication.constraint.is_some()
+            || subtype_indication.type_mark.item.attr.is_some()
+        {
+            return;
+        }
+
+        let typ = subtype.type_mark();
+        if typ.is_unconstrained_array() {
+            diagnostics.error(
+                &subtype_indication.type_mark.pos,
+                format!(
+                    "Unconstrained {} is not allowed in a {} declaration",
+                    typ.describe(),
+                    object_decl.class
+                ),
+            );
+        }
+    }
+
     fn find_deferred_constant_declaration(
         &self,
         scope: &Scope<'a>,
@@ -602,6 +761,148 @@ impl<'a> AnalyzeContext<'a> {
         None
     }
 
+    fn configuration_specification(
+        &self,
+        scope: &Scope<'a>,
+        config: &mut ConfigurationSpecification,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let ConfigurationSpecification { spec, bind_ind, .. } = config;
+        let component_name = &mut spec.component_name;
+
+        let Some(resolved) = as_fatal(self.name_resolve(
+            scope,
+            &component_name.pos,
+            &mut component_name.item,
+            diagnostics,
+        ))?
+        else {
+            return Ok(());
+        };
+
+        let (component, component_region) = match resolved {
+            ResolvedName::Final(ent) => match ent.kind() {
+                AnyEntKind::Component(region) => (ent, region),
+                _ => {
+                    diagnostics.push(resolved.kind_error(component_name.suffix_pos(), "component"));
+                    return Ok(());
+                }
+            },
+            _ => {
+                diagnostics.push(resolved.kind_error(component_name.suffix_pos(), "component"));
+                return Ok(());
+            }
+        };
+
+        let Some(EntityAspect::Entity(entity_name, _)) = &mut bind_ind.entity_aspect else {
+            return Ok(());
+        };
+
+        let Some(resolved) = as_fatal(self.name_resolve(
+            scope,
+            &entity_name.pos,
+            &mut entity_name.item,
+            diagnostics,
+        ))?
+        else {
+            return Ok(());
+        };
+
+        let (entity, entity_region) = match resolved {
+            ResolvedName::Design(DesignEnt(ent)) => match ent.kind() {
+                AnyEntKind::Design(Design::Entity(_, region)) => (ent, region),
+                _ => {
+                    diagnostics.push(resolved.kind_error(entity_name.suffix_pos(), "entity"));
+                    return Ok(());
+                }
+            },
+            _ => {
+                diagnostics.push(resolved.kind_error(entity_name.suffix_pos(), "entity"));
+                return Ok(());
+            }
+        };
+
+        // Explicit generic and port maps may associate the component interface
+        // with the entity interface in any way so only the default binding is checked
+        let (component_generics, component_ports) = component_region.to_entity_formal();
+        let (entity_generics, entity_ports) = entity_region.to_entity_formal();
+
+        if bind_ind.generic_map.is_none() {
+            check_default_binding(
+                &entity_name.pos,
+                component,
+                &component_generics,
+                entity,
+                &entity_generics,
+                diagnostics,
+            );
+        }
+
+        if bind_ind.port_map.is_none() {
+            check_default_binding(
+                &entity_name.pos,
+                component,
+                &component_ports,
+                entity,
+                &entity_ports,
+                diagnostics,
+            );
+        }
+
+        Ok(())
+    }
+
+    fn disconnection_specification(
+        &self,
+        scope: &Scope<'a>,
+        disconnection: &mut DisconnectionSpecification,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let DisconnectionSpecification {
+            signal_list,
+            type_mark,
+            after,
+            span: _,
+        } = disconnection;
+
+        let typ = as_fatal(self.resolve_type_mark(scope, type_mark, diagnostics))?;
+
+        if let GuardedSignalList::Names(names) = signal_list {
+            for name in names.iter_mut() {
+                let Some(object_name) = as_fatal(self.resolve_object_name(
+                    scope,
+                    &name.pos,
+                    &mut name.item,
+                    "is not a signal",
+                    diagnostics,
+                ))?
+                else {
+                    continue;
+                };
+
+                if object_name.base.class() != ObjectClass::Signal {
+                    diagnostics.error(
+                        &name.pos,
+                        format!("{} is not a signal", object_name.base.describe_class()),
+                    );
+                } else if let Some(typ) = typ {
+                    if object_name.type_mark().base() != typ.base() {
+                        diagnostics.error(
+                            &name.pos,
+                            format!(
+                                "{} does not match {}",
+                                object_name.describe_type(),
+                                typ.describe()
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+
+        self.expr_with_ttyp(scope, self.time(), after, diagnostics)
+    }
+
     fn attribute_specification(
         &self,
         scope: &Scope<'a>,
@@ -1032,3 +1333,98 @@ fn find_full_type_definition<'a>(
     }
     None
 }
+
+/// Check that each formal of a component has a formal with the same name and
+/// type in the entity it is bound to
+fn check_default_binding(
+    pos: &SrcPos,
+    component: EntRef,
+    component_region: &FormalRegion,
+    entity: EntRef,
+    entity_region: &FormalRegion,
+    diagnostics: &mut dyn DiagnosticHandler,
+) {
+    let kind = match component_region.typ {
+        InterfaceType::Port => "port",
+        InterfaceType::Generic => "generic",
+        InterfaceType::Parameter => "parameter",
+    };
+
+    for formal in component_region.iter() {
+        let Some(actual) = entity_region
+            .iter()
+            .find(|actual| actual.designator() == formal.designator())
+        else {
+            let mut diag = Diagnostic::error(
+                pos,
+                format!(
+                    "No {kind} '{}' within {} to bind {} to",
+                    formal.designator(),
+                    entity.describe(),
+                    component.describe()
+                ),
+            );
+            if let Some(decl_pos) = formal.decl_pos() {
+                diag.add_related(decl_pos, "Defined here");
+            }
+            diagnostics.push(diag);
+            continue;
+        };
+
+        let formal_type = formal.base_type();
+        let actual_type = actual.base_type();
+
+        if formal_type != actual_type
+            && !matches!(formal_type.kind(), Type::Interface)
+            && !matches!(actual_type.kind(), Type::Interface)
+        {
+            let mut diag = Diagnostic::error(
+                pos,
+                format!(
+                    "{kind} '{}' of {} does not match {} of {}",
+                    formal.designator(),
+                    component.describe(),
+                    actual_type.describe(),
+                    entity.describe()
+                ),
+            );
+            if let Some(decl_pos) = formal.decl_pos() {
+                diag.add_related(
+                    decl_pos,
+                    format!("Declared with {}", formal_type.describe()),
+                );
+            }
+            diagnostics.push(diag);
+        }
+    }
+}
+
+/// Collect the named entities that are referenced within a declaration or expression
+/// together with the position of their first reference
+#[derive(Default)]
+pub(super) struct ReferenceCollector {
+    pub references: Vec<(EntityId, SrcPos)>,
+    pub ids: FnvHashSet<EntityId>,
+}
+
+impl ReferenceCollector {
+    pub fn contains(&self, id: EntityId) -> bool {
+        self.ids.contains(&id)
+    }
+}
+
+impl Searcher for ReferenceCollector {
+    fn search_pos_with_ref(
+        &mut self,
+        _ctx: &dyn TokenAccess,
+        pos: &SrcPos,
+        reference: &Reference,
+    ) -> SearchState {
+        if let Some(id) = reference.get() {
+            if self.ids.insert(id) {
+                self.references.push((id, pos.clone()));
+            }
+        }
+        NotFinished
+    }
+}
@@ -114,6 +114,12 @@ impl<'a> AnalyzeContext<'a> {
                     ));
                 }
             }
+
+            self.check_unmatched_component_configurations(
+                named_entity,
+                &unit.block_config,
+                diagnostics,
+            )?;
         };
 
         self.arena.define(
@@ -300,12 +306,20 @@ impl<'a> AnalyzeContext<'a> {
             Design::Package(ref visibility, ref region)
             | Design::UninstPackage(ref visibility, ref region) => (visibility, region),
             _ => {
-                let mut diagnostic = Diagnostic::error(unit.pos(), "Expected a package");
+                let mut diagnostic = Diagnostic::error(
+                    &unit.ident.tree.pos,
+                    format!(
+                        "No package '{}' for package body within library '{}'",
+                        unit.ident.tree.item,
+                        self.work_library_name()
+                    ),
+                );
 
                 if let Some(pos) = primary.decl_pos() {
                     diagnostic.add_related(pos, format!("Found {}", primary.describe()))
                 }
 
+                diagnostics.push(diagnostic);
                 return Ok(());
             }
         };
@@ -359,6 +373,109 @@ impl<'a> AnalyzeContext<'a> {
         }
     }
 
+    /// Warn about `all` and `others` component configurations that match no instance
+    fn check_unmatched_component_configurations(
+        &self,
+        entity: DesignEnt<'a>,
+        block_config: &BlockConfiguration,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let has_component_configurations = block_config
+            .items
+            .iter()
+            .any(|item| matches!(item, ConfigurationItem::Component(_)));
+
+        // Avoid depending on the architecture unless necessary
+        if !has_component_configurations {
+            return Ok(());
+        }
+
+        let (Designator::Identifier(entity_name), Name::Designator(arch_name)) =
+            (entity.designator(), &block_config.block_spec.item)
+        else {
+            return Ok(());
+        };
+        let Designator::Identifier(arch_name) = &arch_name.item else {
+            return Ok(());
+        };
+        let Some(unit) = self.get_secondary_unit(self.work_library_name(), entity_name, arch_name)
+        else {
+            return Ok(());
+        };
+        let data = self.get_analysis(Some(&block_config.block_spec.pos), unit)?;
+        let AnyDesignUnit::Secondary(AnySecondaryUnit::Architecture(arch)) = &*data else {
+            return Ok(());
+        };
+
+        // The label and component name of each component instance
+        let instances: Vec<(&Symbol, &Designator)> = arch
+            .statements
+            .iter()
+            .filter_map(|stmt| match stmt.statement.item {
+                ConcurrentStatement::Instance(ref instance) => match instance.unit {
+                    InstantiatedUnit::Component(ref name) => Some((
+                        &stmt.label.tree.as_ref()?.item,
+                        component_designator(&name.item)?,
+                    )),
+                    _ => None,
+                },
+                // A component instance without maps is parsed as a procedure call
+                ConcurrentStatement::ProcedureCall(ref pcall) => {
+                    let name = &pcall.call.item.name.item;
+                    let id = name.get_suffix_reference()?;
+                    if matches!(self.arena.get(id).kind(), AnyEntKind::Component(_)) {
+                        Some((&stmt.label.tree.as_ref()?.item, component_designator(name)?))
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            })
+            .collect();
+
+        let specs: Vec<(&InstantiationList, &WithPos<Name>, &Designator)> = block_config
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                ConfigurationItem::Component(ComponentConfiguration { spec, .. }) => Some((
+                    &spec.instantiation_list,
+                    &spec.component_name,
+                    component_designator(&spec.component_name.item)?,
+                )),
+                ConfigurationItem::Block(_) => None,
+            })
+            .collect();
+
+        let is_labeled = |label: &Symbol, component: &Designator| {
+            specs.iter().any(|(list, _, other)| match list {
+                InstantiationList::Labels(labels) => {
+                    *other == component && labels.iter().any(|ident| &ident.item == label)
+                }
+                _ => false,
+            })
+        };
+
+        for (list, component_name, component) in specs.iter() {
+            let keyword = match list {
+                InstantiationList::All => "all",
+                InstantiationList::Others => "others",
+                InstantiationList::Labels(_) => continue,
+            };
+
+            if !instances.iter().any(|(label, other)| {
+                other == component
+                    && (matches!(list, InstantiationList::All) || !is_labeled(label, component))
+            }) {
+                diagnostics.warning(
+                    &component_name.pos,
+                    format!("'{keyword}' does not match any instance of component '{component}'"),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     fn lookup_entity_for_configuration(
         &self,
         scope: &Scope<'a>,
@@ -506,7 +623,7 @@ impl<'a> AnalyzeContext<'a> {
                 ContextItem::Library(LibraryClause {
                     ref mut name_list, ..
                 }) => {
-                    for library_name in name_list.items.iter_mut() {
+                    for library_name in name_list.iter_mut() {
                         if self.work_sym == library_name.item.item {
                             library_name.set_unique_reference(self.work_library());
                             diagnostics.push(Diagnostic::hint(
@@ -530,7 +647,7 @@ impl<'a> AnalyzeContext<'a> {
                 ContextItem::Context(ContextReference {
                     ref mut name_list, ..
                 }) => {
-                    for name in name_list.items.iter_mut() {
+                    for name in name_list.iter_mut() {
                         match name.item {
                             Name::Selected(..) => {}
                             _ => {
@@ -590,7 +707,7 @@ impl<'a> AnalyzeContext<'a> {
         use_clause: &mut UseClause,
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> FatalResult {
-        for name in use_clause.name_list.items.iter_mut() {
+        for name in use_clause.name_list.iter_mut() {
             match name.item {
                 Name::Selected(..) => {}
                 Name::SelectedAll(..) => {}
@@ -677,3 +794,11 @@ pub enum UsedNames<'a> {
     /// @TODO add pos for where declaration was made visible into VisibleDeclaration
     AllWithin(SrcPos, EntRef<'a>),
 }
+
+fn component_designator(name: &Name) -> Option<&Designator> {
+    match name {
+        Name::Designator(designator) => Some(&designator.item),
+        Name::Selected(_, designator) => Some(&designator.item.item),
+        _ => None,
+    }
+}
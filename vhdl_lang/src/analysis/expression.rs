@@ -11,7 +11,9 @@ use super::analyze::*;
 use super::overloaded::Disambiguated;
 use super::overloaded::DisambiguatedType;
 use super::overloaded::ResolvedCall;
+use super::range::static_discrete_range;
 use super::scope::*;
+use crate::ast;
 use crate::ast::*;
 use crate::data::*;
 use crate::named_entity::*;
@@ -502,7 +504,7 @@ impl<'a> AnalyzeContext<'a> {
                 Literal::String(_) => Ok(ExpressionType::String),
                 Literal::BitString(_) => Ok(ExpressionType::String),
                 Literal::Character(chr) => {
-                    match scope.lookup(expr_pos, &Designator::Character(*chr)) {
+                    match scope.lookup(expr_pos, &Designator::Character(chr.item)) {
                         Ok(NamedEntities::Single(ent)) => {
                             // Should never happen but better know if it does
                             diagnostics.error(
@@ -518,6 +520,7 @@ impl<'a> AnalyzeContext<'a> {
                             if overloaded.len() == 1 {
                                 let ent = overloaded.first();
                                 if let Some(return_type) = ent.return_type() {
+                                    chr.set_unique_reference(&ent);
                                     Ok(ExpressionType::Unambiguous(return_type))
                                 } else {
                                     diagnostics.error(
@@ -578,6 +581,12 @@ impl<'a> AnalyzeContext<'a> {
 
         match as_fatal(self.resolve_type_mark(scope, type_mark, diagnostics))? {
             Some(target_type) => {
+                self.check_aggregate_index_range(
+                    scope,
+                    Subtype::new(target_type),
+                    expr,
+                    diagnostics,
+                )?;
                 self.expr_pos_with_ttyp(
                     scope,
                     target_type,
@@ -835,6 +844,7 @@ impl<'a> AnalyzeContext<'a> {
                 Type::Array {
                     elem_type, indexes, ..
                 } => {
+                    check_array_aggregate_choices(assocs, target_type.index_range(), diagnostics);
                     for assoc in assocs.iter_mut() {
                         as_fatal(self.array_assoc_elem(
                             scope,
@@ -1151,7 +1161,6 @@ impl<'a> AnalyzeContext<'a> {
                             }
                         }
                         Choice::Others => {
-                            // @TODO choice must be alone so cannot appear here
                             can_be_array = false;
                         }
                     }
@@ -1163,6 +1172,7 @@ impl<'a> AnalyzeContext<'a> {
 
         if index_types.len() > 1 {
             if let Expression::Aggregate(ref mut inner) = expr.item {
+                check_array_aggregate_choices(inner, None, diagnostics);
                 for assoc in inner.iter_mut() {
                     as_fatal(self.array_assoc_elem(
                         scope,
@@ -1249,6 +1259,324 @@ impl<'a> RecordAssociations<'a> {
     }
 }
 
+/// Index ranges that have been associated by the choices of an array aggregate
+struct ArrayAssociations<'a> {
+    // The index range of the array type when statically known
+    index_range: Option<StaticRange>,
+    associated: Vec<(i64, i64, &'a SrcPos)>,
+}
+
+impl<'a> ArrayAssociations<'a> {
+    fn associate(
+        &mut self,
+        low: i64,
+        high: i64,
+        pos: &'a SrcPos,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        if low > high {
+            // A null range does not associate anything
+            return;
+        }
+
+        if let Some(index_range) = self.index_range {
+            if let Some(index) = index_outside_of_range(index_range, low, high) {
+                diagnostics.error(
+                    pos,
+                    format!("Index {index} is outside of the index range {index_range}"),
+                );
+                return;
+            }
+        }
+
+        for (prev_low, prev_high, prev_pos) in self.associated.iter() {
+            if low <= *prev_high && *prev_low <= high {
+                diagnostics.push(
+                    Diagnostic::error(
+                        pos,
+                        format!("Index {} has already been associated", low.max(*prev_low)),
+                    )
+                    .related(*prev_pos, "Previously associated here"),
+                );
+                return;
+            }
+        }
+
+        self.associated.push((low, high, pos));
+    }
+}
+
+/// LRM 9.3.3.3 Array aggregates
+/// Positional and named associations may not be mixed except for a final others choice.
+/// Choices that are locally static integer values may not overlap
+/// and must be within the index range of the array type when it is statically known.
+fn check_array_aggregate_choices(
+    assocs: &[ElementAssociation],
+    index_range: Option<StaticRange>,
+    diagnostics: &mut dyn DiagnosticHandler,
+) {
+    let mut associated = ArrayAssociations {
+        index_range,
+        associated: Vec::new(),
+    };
+    let mut is_positional = false;
+    let mut is_named = false;
+
+    for (idx, assoc) in assocs.iter().enumerate() {
+        match assoc {
+            ElementAssociation::Positional(expr) => {
+                if is_named {
+                    diagnostics.error(
+                        &expr.pos,
+                        "Positional associations cannot follow named associations in an array aggregate",
+                    );
+                }
+                is_positional = true;
+            }
+            ElementAssociation::Named(choices, _) => {
+                for choice in choices.iter() {
+                    if let Choice::Others = choice.item {
+                        if choices.len() > 1 || idx + 1 != assocs.len() {
+                            diagnostics.error(
+                                &choice.pos,
+                                "'others' must be the only choice of the last association",
+                            );
+                        }
+                        continue;
+                    }
+
+                    if is_positional && !is_named {
+                        diagnostics.error(
+                            &choice.pos,
+                            "Named associations cannot follow positional associations in an array aggregate",
+                        );
+                    }
+                    is_named = true;
+
+                    match &choice.item {
+                        Choice::Expression(expr) => {
+                            if let Some(value) = static_integer(expr) {
+                                associated.associate(value, value, &choice.pos, diagnostics);
+                            }
+                        }
+                        Choice::DiscreteRange(DiscreteRange::Range(ast::Range::Range(
+                            constraint,
+                        ))) => {
+                            if let (Some(left), Some(right)) = (
+                                static_integer(&constraint.left_expr.item),
+                                static_integer(&constraint.right_expr.item),
+                            ) {
+                                let (low, high) = match constraint.direction {
+                                    Direction::Ascending => (left, right),
+                                    Direction::Descending => (right, left),
+                                };
+                                associated.associate(low, high, &choice.pos, diagnostics);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The lowest and highest index associated by a choice that is locally static
+fn static_choice_bounds(choice: &Choice) -> Option<(i64, i64)> {
+    match choice {
+        Choice::Expression(expr) => {
+            let value = static_integer(expr)?;
+            Some((value, value))
+        }
+        Choice::DiscreteRange(drange) => Some(static_discrete_range(drange)?.bounds()),
+        Choice::Others => None,
+    }
+}
+
+/// The first index from `low` to `high` that is outside of the index range
+fn index_outside_of_range(index_range: StaticRange, low: i64, high: i64) -> Option<i64> {
+    let (index_low, index_high) = index_range.bounds();
+    if low < index_low {
+        Some(low)
+    } else if high > index_high {
+        Some(high)
+    } else {
+        None
+    }
+}
+
+impl<'a> AnalyzeContext<'a> {
+    /// LRM 9.3.3.3 Array aggregates
+    /// An others choice requires the index range of the aggregate to be determined by the context,
+    /// an unconstrained array subtype does not determine the index range.
+    /// Without an others choice the associations must cover the index range of a constrained subtype.
+    pub fn check_aggregate_index_range(
+        &self,
+        scope: &Scope<'a>,
+        subtype: Subtype<'a>,
+        expr: &mut WithPos<Expression>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let Expression::Aggregate(ref mut assocs) = expr.item else {
+            return Ok(());
+        };
+
+        let type_mark = subtype.type_mark();
+        if subtype.is_unconstrained_array() {
+            for assoc in assocs.iter() {
+                if let ElementAssociation::Named(choices, _) = assoc {
+                    for choice in choices.iter() {
+                        if let Choice::Others = choice.item {
+                            diagnostics.error(
+                                &choice.pos,
+                                format!(
+                                    "'others' is not allowed since the index range of unconstrained {} is not known",
+                                    type_mark.describe()
+                                ),
+                            );
+                        }
+                    }
+                }
+            }
+            return Ok(());
+        }
+
+        let Some(index_range) = subtype.index_range() else {
+            return Ok(());
+        };
+
+        // The choices are checked against the index range of the type mark with the aggregate
+        // itself, only the index constraint of the subtype indication needs to be checked here
+        if type_mark.index_range().is_none() {
+            for assoc in assocs.iter() {
+                if let ElementAssociation::Named(choices, _) = assoc {
+                    for choice in choices.iter() {
+                        let Some((low, high)) = static_choice_bounds(&choice.item) else {
+                            continue;
+                        };
+                        if low > high {
+                            continue;
+                        }
+                        if let Some(index) = index_outside_of_range(index_range, low, high) {
+                            diagnostics.error(
+                                &choice.pos,
+                                format!(
+                                    "Index {index} is outside of the index range {index_range}"
+                                ),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        let Type::Array { elem_type, .. } = subtype.base_type().kind() else {
+            return Ok(());
+        };
+
+        if let Some(index) =
+            self.first_unassociated_index(scope, *elem_type, assocs, index_range)?
+        {
+            diagnostics.error(
+                &expr.pos,
+                format!(
+                    "Index {index} of {} is not associated, consider an 'others' choice",
+                    type_mark.describe()
+                ),
+            );
+        }
+        Ok(())
+    }
+
+    /// The lowest index of the index range that is not associated by an aggregate.
+    /// None if all indexes are associated, if there is an others choice,
+    /// if a choice is not a locally static integer value
+    /// or if a positional association is not of the element type such as a slice.
+    fn first_unassociated_index(
+        &self,
+        scope: &Scope<'a>,
+        elem_type: TypeEnt<'a>,
+        assocs: &mut [ElementAssociation],
+        index_range: StaticRange,
+    ) -> FatalResult<Option<i64>> {
+        let mut associated = Vec::new();
+        let mut next_positional = index_range.left;
+
+        for assoc in assocs.iter_mut() {
+            match assoc {
+                ElementAssociation::Positional(expr) => {
+                    let Some(types) = as_fatal(self.expr_type(scope, expr, &mut NullDiagnostics))?
+                    else {
+                        return Ok(None);
+                    };
+                    if !self.is_possible(&types, elem_type.base()) {
+                        return Ok(None);
+                    }
+
+                    associated.push((next_positional, next_positional));
+                    next_positional = match index_range.direction {
+                        Direction::Ascending => next_positional.checked_add(1),
+                        Direction::Descending => next_positional.checked_sub(1),
+                    }
+                    .unwrap_or(next_positional);
+                }
+                ElementAssociation::Named(choices, _) => {
+                    for choice in choices.iter() {
+                        if let Choice::Others = choice.item {
+                            return Ok(None);
+                        }
+                        let Some(bounds) = static_choice_bounds(&choice.item) else {
+                            return Ok(None);
+                        };
+                        associated.push(bounds);
+                    }
+                }
+            }
+        }
+
+        associated.sort_unstable();
+
+        let (mut index, index_high) = index_range.bounds();
+        for (low, high) in associated {
+            if low > index {
+                break;
+            }
+            let Some(next) = high.checked_add(1) else {
+                return Ok(None);
+            };
+            index = index.max(next);
+        }
+
+        Ok((index <= index_high).then_some(index))
+    }
+}
+
+/// Evaluate an integer expression made of literals and the arithmetic adding and multiplying operators
+pub(super) fn static_integer(expr: &Expression) -> Option<i64> {
+    match expr {
+        Expression::Literal(_) => expr.as_integer_literal(),
+        Expression::Unary(op, operand) => {
+            let value = static_integer(&operand.item)?;
+            match op.item.item {
+                Operator::Plus => Some(value),
+                Operator::Minus => value.checked_neg(),
+                _ => None,
+            }
+        }
+        Expression::Binary(op, left, right) => {
+            let left = static_integer(&left.item)?;
+            let right = static_integer(&right.item)?;
+            match op.item.item {
+                Operator::Plus => left.checked_add(right),
+                Operator::Minus => left.checked_sub(right),
+                Operator::Times => left.checked_mul(right),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
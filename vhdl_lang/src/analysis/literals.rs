@@ -73,9 +73,21 @@ impl<'a> AnalyzeContext<'a> {
                     }
                 }
             },
-            Literal::Character(char) => match target_base.kind() {
+            Literal::Character(chr) => match target_base.kind() {
                 Type::Enum(literals) => {
-                    if !literals.contains(&Designator::Character(*char)) {
+                    let designator = Designator::Character(chr.item);
+                    if literals.contains(&designator) {
+                        // The literal may be overloaded by several enumeration types
+                        if let Ok(NamedEntities::Overloaded(overloaded)) =
+                            scope.lookup(pos, &designator)
+                        {
+                            if let Some(ent) = overloaded.entities().find(|ent| {
+                                ent.return_type().map(|typ| typ.base()) == Some(target_base.base())
+                            }) {
+                                chr.set_unique_reference(&ent);
+                            }
+                        }
+                    } else {
                         diagnostics.push(Diagnostic::error(
                             pos,
                             format!(
@@ -11,6 +11,8 @@ use super::expression::ExpressionType;
 use super::overloaded::Disambiguated;
 use super::overloaded::DisambiguatedType;
 use super::overloaded::SubprogramKind;
+use super::range::static_discrete_range;
+use super::root::DesignRoot;
 use super::scope::*;
 use crate::ast::*;
 use crate::data::*;
@@ -83,6 +85,8 @@ impl<'a> ObjectBase<'a> {
 pub struct ObjectName<'a> {
     pub base: ObjectBase<'a>,
     pub type_mark: Option<TypeEnt<'a>>,
+    // The statically known index range of a slice
+    pub constraint: Option<StaticRange>,
 }
 
 impl<'a> ObjectName<'a> {
@@ -96,10 +100,22 @@ impl<'a> ObjectName<'a> {
         }
     }
 
-    fn with_suffix(self, type_mark: TypeEnt<'a>) -> Self {
+    /// The subtype of the object including any constraint that is statically known,
+    /// for example the narrowed index range of a slice
+    pub fn effective_subtype(&self) -> Subtype<'a> {
+        if self.type_mark.is_none() {
+            if let ObjectBase::Object(obj) = self.base {
+                return obj.kind().subtype;
+            }
+        }
+        Subtype::with_constraint(self.type_mark(), self.constraint)
+    }
+
+    fn with_suffix(self, type_mark: TypeEnt<'a>, constraint: Option<StaticRange>) -> Self {
         ObjectName {
             base: self.base,
             type_mark: Some(type_mark),
+            constraint,
         }
     }
 
@@ -131,12 +147,64 @@ pub enum ResolvedName<'a> {
 }
 
 impl<'a> ResolvedName<'a> {
+    /// The same resolved name with the named entities looked up in the design root.
+    /// Used when a name is resolved with a temporary arena that does not outlive the root.
+    pub(super) fn rebind<'r>(&self, root: &'r DesignRoot) -> ResolvedName<'r> {
+        let ent = |id: EntityId| root.get_ent(id);
+        let typ = |typ: TypeEnt| TypeEnt::from_any(ent(typ.id())).unwrap();
+        let object = |obj: ObjectEnt| ObjectEnt::from_any(ent(obj.id())).unwrap();
+
+        match self {
+            ResolvedName::Library(sym) => ResolvedName::Library(sym.clone()),
+            ResolvedName::Design(design) => {
+                ResolvedName::Design(DesignEnt::from_any(ent(design.id())).unwrap())
+            }
+            ResolvedName::Type(t) => ResolvedName::Type(typ(*t)),
+            ResolvedName::Overloaded(des, overloaded) => ResolvedName::Overloaded(
+                des.clone(),
+                OverloadedName::new(
+                    overloaded
+                        .entities()
+                        .map(|o| OverloadedEnt::from_any(ent(o.id())).unwrap())
+                        .collect(),
+                ),
+            ),
+            ResolvedName::ObjectName(oname) => ResolvedName::ObjectName(ObjectName {
+                base: match oname.base {
+                    ObjectBase::Object(obj) => ObjectBase::Object(object(obj)),
+                    ObjectBase::ObjectAlias(obj, alias) => {
+                        ObjectBase::ObjectAlias(object(obj), ent(alias.id()))
+                    }
+                    ObjectBase::DeferredConstant(constant) => {
+                        ObjectBase::DeferredConstant(ent(constant.id()))
+                    }
+                    ObjectBase::ExternalName(class) => ObjectBase::ExternalName(class),
+                },
+                type_mark: oname.type_mark.map(typ),
+                constraint: oname.constraint,
+            }),
+            ResolvedName::Expression(DisambiguatedType::Unambiguous(t)) => {
+                ResolvedName::Expression(DisambiguatedType::Unambiguous(typ(*t)))
+            }
+            ResolvedName::Expression(DisambiguatedType::Ambiguous(types)) => {
+                ResolvedName::Expression(DisambiguatedType::Ambiguous(
+                    types
+                        .iter()
+                        .map(|base| TypeEnt::from_any(ent(base.id())).unwrap().base())
+                        .collect(),
+                ))
+            }
+            ResolvedName::Final(final_ent) => ResolvedName::Final(ent(final_ent.id())),
+        }
+    }
+
     /// The name was selected out of a design unit
     fn from_design_not_overloaded(ent: &'a AnyEnt) -> Result<Self, String> {
         let name = match ent.kind() {
             AnyEntKind::Object(_) => ResolvedName::ObjectName(ObjectName {
                 base: ObjectBase::Object(ObjectEnt::from_any(ent).unwrap()),
                 type_mark: None,
+                constraint: None,
             }),
             AnyEntKind::ObjectAlias {
                 base_object,
@@ -144,16 +212,19 @@ impl<'a> ResolvedName<'a> {
             } => ResolvedName::ObjectName(ObjectName {
                 base: ObjectBase::ObjectAlias(*base_object, ent),
                 type_mark: Some(type_mark.to_owned()),
+                constraint: None,
             }),
             AnyEntKind::ExternalAlias { class, type_mark } => {
                 ResolvedName::ObjectName(ObjectName {
                     base: ObjectBase::ExternalName(*class),
                     type_mark: Some(*type_mark),
+                    constraint: None,
                 })
             }
             AnyEntKind::DeferredConstant(subtype) => ResolvedName::ObjectName(ObjectName {
                 base: ObjectBase::DeferredConstant(ent),
                 type_mark: Some(subtype.type_mark()),
+                constraint: None,
             }),
             AnyEntKind::Type(_) => ResolvedName::Type(TypeEnt::from_any(ent).unwrap()),
             AnyEntKind::Overloaded(_) => {
@@ -190,6 +261,7 @@ impl<'a> ResolvedName<'a> {
             AnyEntKind::Object(_) => ResolvedName::ObjectName(ObjectName {
                 base: ObjectBase::Object(ObjectEnt::from_any(ent).unwrap()),
                 type_mark: None,
+                constraint: None,
             }),
             AnyEntKind::ObjectAlias {
                 base_object,
@@ -197,16 +269,19 @@ impl<'a> ResolvedName<'a> {
             } => ResolvedName::ObjectName(ObjectName {
                 base: ObjectBase::ObjectAlias(*base_object, ent),
                 type_mark: Some(type_mark.to_owned()),
+                constraint: None,
             }),
             AnyEntKind::ExternalAlias { class, type_mark } => {
                 ResolvedName::ObjectName(ObjectName {
                     base: ObjectBase::ExternalName(*class),
                     type_mark: Some(*type_mark),
+                    constraint: None,
                 })
             }
             AnyEntKind::DeferredConstant(subtype) => ResolvedName::ObjectName(ObjectName {
                 base: ObjectBase::DeferredConstant(ent),
                 type_mark: Some(subtype.type_mark()),
+                constraint: None,
             }),
             AnyEntKind::Type(_) => ResolvedName::Type(TypeEnt::from_any(ent).unwrap()),
             AnyEntKind::Design(_) => ResolvedName::Design(DesignEnt::from_any(ent).unwrap()),
@@ -336,6 +411,30 @@ impl<'a> ResolvedName<'a> {
         Err(EvalError::Unknown)
     }
 
+    /// The prefix of the 'driving and 'driving_value attributes must be a signal
+    /// that may be driven, ports of mode in or linkage are not allowed
+    fn check_driving_attr_prefix(
+        &self,
+        prefix_pos: &SrcPos,
+        attr: &AttributeSuffix,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> EvalResult<()> {
+        if let ResolvedName::ObjectName(oname) = self {
+            if matches!(oname.base.mode(), Some(Mode::In | Mode::Linkage)) {
+                diagnostics.error(
+                    prefix_pos,
+                    format!(
+                        "'{} attribute cannot be used with {}",
+                        attr.attr,
+                        oname.base.describe_class()
+                    ),
+                );
+                return Err(EvalError::Unknown);
+            }
+        }
+        Ok(())
+    }
+
     // The actual underlying entity
     fn as_actual_entity(&self) -> Option<EntRef<'a>> {
         match self {
@@ -966,10 +1065,12 @@ impl<'a> AnalyzeContext<'a> {
                     }
                     SignalAttribute::Driving => {
                         check_no_sattr_argument(sattr, expr, diagnostics);
+                        prefix.check_driving_attr_prefix(prefix_pos, attr, diagnostics)?;
                         Ok(AttrResolveResult::Value(self.boolean().base()))
                     }
                     SignalAttribute::DrivingValue => {
                         check_no_sattr_argument(sattr, expr, diagnostics);
+                        prefix.check_driving_attr_prefix(prefix_pos, attr, diagnostics)?;
                         Ok(AttrResolveResult::Value(typ.base()))
                     }
                 }
@@ -1097,6 +1198,7 @@ impl<'a> AnalyzeContext<'a> {
                 return Ok(ResolvedName::ObjectName(ObjectName {
                     base: ObjectBase::ExternalName(*class),
                     type_mark: Some(subtype.type_mark().to_owned()),
+                    constraint: None,
                 }));
             }
             SplitName::Suffix(p, s) => {
@@ -1246,6 +1348,10 @@ impl<'a> AnalyzeContext<'a> {
                 }
             }
             ResolvedName::ObjectName(oname) => {
+                let constraint = match suffix {
+                    Suffix::Slice(ref drange) => static_discrete_range(drange),
+                    _ => None,
+                };
                 match self.resolve_typed_suffix(
                     scope,
                     &prefix.pos,
@@ -1255,7 +1361,7 @@ impl<'a> AnalyzeContext<'a> {
                     diagnostics,
                 )? {
                     Some(TypeOrMethod::Type(typ)) => {
-                        resolved = ResolvedName::ObjectName(oname.with_suffix(typ));
+                        resolved = ResolvedName::ObjectName(oname.with_suffix(typ, constraint));
                     }
                     Some(TypeOrMethod::Method(des, name)) => {
                         resolved = ResolvedName::Overloaded(des, name);
@@ -1677,6 +1783,7 @@ impl Declaration {
             Declaration::Use(_) => "use",
             Declaration::Package(_) => "package instantiation",
             Declaration::Configuration(_) => "configuration",
+            Declaration::Disconnection(_) => "disconnection specification",
         }
     }
 }
@@ -1881,6 +1988,42 @@ mod test {
         }
     }
 
+    #[test]
+    fn resolve_all_returns_every_overloaded_candidate() {
+        let test = TestSetup::new();
+        let decl = test.declarative_part(
+            "
+function myfun(arg : integer) return integer;
+function myfun(arg : character) return integer;
+constant myconst : natural := 0;
+        ",
+        );
+
+        let des = test.snippet("myfun").designator();
+        let candidates: Vec<_> = test
+            .scope
+            .resolve_all(&des.pos, &des.item)
+            .into_iter()
+            .map(|ent| ent.decl_pos().cloned())
+            .collect();
+        assert_eq!(
+            candidates,
+            vec![
+                Some(decl.s("myfun", 1).pos()),
+                Some(decl.s("myfun", 2).pos())
+            ]
+        );
+
+        let des = test.snippet("myconst").designator();
+        assert_eq!(
+            test.scope.resolve_all(&des.pos, &des.item),
+            vec![test.lookup("myconst")]
+        );
+
+        let des = test.snippet("missing").designator();
+        assert!(test.scope.resolve_all(&des.pos, &des.item).is_empty());
+    }
+
     #[test]
     fn consecutive_name_attributes() {
         let test = TestSetup::new();
@@ -2268,6 +2411,50 @@ variable c0 : integer_vector(0 to 6);
         );
     }
 
+    #[test]
+    fn effective_subtype_of_slice() {
+        let test = TestSetup::new();
+        test.declarative_part(
+            "
+subtype byte_t is bit_vector(7 downto 0);
+variable v : byte_t;
+variable w : bit_vector(0 to 15);
+",
+        );
+
+        let effective_length = |name: &str| {
+            let code = test.snippet(name);
+            match test.name_resolve(&code, None, &mut NoDiagnostics) {
+                Ok(ResolvedName::ObjectName(oname)) => oname.effective_subtype().length(),
+                _ => panic!("Expected object name"),
+            }
+        };
+
+        assert_eq!(effective_length("v"), Some(8));
+        assert_eq!(effective_length("v(3 downto 0)"), Some(4));
+        assert_eq!(effective_length("w"), Some(16));
+        assert_eq!(effective_length("w(2 to 2 + 3)"), Some(4));
+        assert_eq!(effective_length("w(3 to 0)"), Some(0));
+        assert_eq!(effective_length("w(0)"), None);
+
+        let code = test.snippet("v(3 downto 0)");
+        let Ok(ResolvedName::ObjectName(oname)) =
+            test.name_resolve(&code, None, &mut NoDiagnostics)
+        else {
+            panic!("Expected object name");
+        };
+        let subtype = oname.effective_subtype();
+        assert_eq!(subtype.type_mark(), test.lookup_type("bit_vector"));
+        assert_eq!(
+            subtype.constraint(),
+            Some(StaticRange {
+                left: 3,
+                direction: Direction::Descending,
+                right: 0
+            })
+        );
+    }
+
     #[test]
     fn slice_with_enum_discrete_range() {
         let test = TestSetup::new();
@@ -2788,6 +2975,43 @@ signal thesig : integer;
         );
     }
 
+    #[test]
+    fn transaction_and_driving_attributes_of_composite_signal() {
+        let test = TestSetup::new();
+        test.declarative_part(
+            "
+type rec_t is record
+    field : natural;
+end record;
+signal thesig : rec_t;
+        ",
+        );
+
+        let code = test.snippet("thesig'driving_value");
+        assert_eq!(
+            test.name_resolve(&code, None, &mut NoDiagnostics),
+            Ok(ResolvedName::Expression(DisambiguatedType::Unambiguous(
+                test.lookup_type("rec_t")
+            )))
+        );
+
+        let code = test.snippet("thesig'transaction");
+        assert_eq!(
+            test.name_resolve(&code, None, &mut NoDiagnostics),
+            Ok(ResolvedName::Expression(DisambiguatedType::Unambiguous(
+                test.ctx().bit()
+            )))
+        );
+
+        let code = test.snippet("thesig'driving");
+        assert_eq!(
+            test.name_resolve(&code, None, &mut NoDiagnostics),
+            Ok(ResolvedName::Expression(DisambiguatedType::Unambiguous(
+                test.ctx().boolean()
+            )))
+        );
+    }
+
     #[test]
     fn missing_attribute() {
         let test = TestSetup::new();
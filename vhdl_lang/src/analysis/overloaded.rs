@@ -608,6 +608,41 @@ function myfun(arg1 : integer) return integer;
         );
     }
 
+    #[test]
+    fn disambiguate_fcall_based_on_literal_type() {
+        let test = TestSetup::new();
+        let decl = test.declarative_part(
+            "
+function myfun(arg1 : real) return integer;
+function myfun(arg1 : integer) return integer;
+        ",
+        );
+        let fcall = test.snippet("myfun(3)");
+        assert_eq!(
+            test.disambiguate(&fcall, None, &mut NoDiagnostics),
+            Some(Disambiguated::Unambiguous(
+                test.lookup_overloaded(decl.s("myfun", 2))
+            ))
+        );
+
+        let fcall = test.snippet("myfun(3.0)");
+        assert_eq!(
+            test.disambiguate(&fcall, None, &mut NoDiagnostics),
+            Some(Disambiguated::Unambiguous(
+                test.lookup_overloaded(decl.s1("myfun"))
+            ))
+        );
+
+        // Universal integer expressions are also typed from the candidates
+        let fcall = test.snippet("myfun(2 * 3 + 1)");
+        assert_eq!(
+            test.disambiguate(&fcall, None, &mut NoDiagnostics),
+            Some(Disambiguated::Unambiguous(
+                test.lookup_overloaded(decl.s("myfun", 2))
+            ))
+        );
+    }
+
     #[test]
     fn ambiguous_call() {
         let test = TestSetup::new();
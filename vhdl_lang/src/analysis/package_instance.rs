@@ -231,7 +231,7 @@ impl<'a> AnalyzeContext<'a> {
             self.generic_map(
                 &nested,
                 generics,
-                generic_map.list.items.as_mut_slice(),
+                generic_map.list.as_mut_slice(),
                 diagnostics,
             )?
         } else {
@@ -488,7 +488,12 @@ impl<'a> AnalyzeContext<'a> {
         typ: &'a Type<'a>,
     ) -> Result<Type<'a>, String> {
         Ok(match typ {
-            Type::Array { indexes, elem_type } => {
+            Type::Array {
+                indexes,
+                elem_type,
+                is_constrained,
+                constraint,
+            } => {
                 let mut mapped_indexes = Vec::with_capacity(indexes.len());
                 for index_typ in indexes.iter() {
                     mapped_indexes.push(
@@ -500,6 +505,8 @@ impl<'a> AnalyzeContext<'a> {
                 Type::Array {
                     indexes: mapped_indexes,
                     elem_type: self.map_type_ent(mapping, *elem_type),
+                    is_constrained: *is_constrained,
+                    constraint: *constraint,
                 }
             }
             Type::Enum(symbols) => Type::Enum(symbols.clone()),
@@ -565,10 +572,16 @@ impl<'a> AnalyzeContext<'a> {
         mapping: &FnvHashMap<EntityId, TypeEnt<'a>>,
         subtype: Subtype<'a>,
     ) -> Result<Subtype<'a>, String> {
-        let Subtype { type_mark } = subtype;
+        let Subtype {
+            type_mark,
+            constraint,
+            has_index_constraint,
+        } = subtype;
 
         Ok(Subtype {
             type_mark: self.map_type_ent(mapping, type_mark),
+            constraint,
+            has_index_constraint,
         })
     }
 }
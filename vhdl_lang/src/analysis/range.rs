@@ -5,7 +5,7 @@
 //! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
 
 use super::analyze::*;
-use super::expression::ExpressionType;
+use super::expression::{static_integer, ExpressionType};
 use super::names::AttributeSuffix;
 use super::names::ResolvedName;
 use super::overloaded::Disambiguated;
@@ -360,6 +360,26 @@ impl<'a> AnalyzeContext<'a> {
     }
 }
 
+/// The bounds of a range whose left and right expressions are static integers
+pub(super) fn static_range(range: &Range) -> Option<StaticRange> {
+    let Range::Range(constraint) = range else {
+        return None;
+    };
+
+    Some(StaticRange {
+        left: static_integer(&constraint.left_expr.item)?,
+        direction: constraint.direction,
+        right: static_integer(&constraint.right_expr.item)?,
+    })
+}
+
+pub(super) fn static_discrete_range(drange: &DiscreteRange) -> Option<StaticRange> {
+    match drange {
+        DiscreteRange::Range(range) => static_range(range),
+        DiscreteRange::Discrete(_, range) => static_range(range.as_ref()?),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::analysis::analyze::EvalError;
@@ -6,6 +6,8 @@
 
 use super::analyze::*;
 use super::lock::*;
+use super::names::ResolvedName;
+use super::scope::Scope;
 use super::standard::StandardTypes;
 use super::standard::UniversalTypes;
 use crate::named_entity::*;
@@ -13,12 +15,13 @@ use crate::named_entity::*;
 use crate::ast::search::*;
 use crate::ast::*;
 use crate::data::*;
-use crate::syntax::{Symbols, Token, TokenAccess};
+use crate::syntax::{parse_name, Kind, Symbols, Token, TokenAccess, TokenStream, Tokenizer};
 use fnv::{FnvHashMap, FnvHashSet};
 use parking_lot::RwLock;
 use std::collections::hash_map::Entry;
 use std::ops::Deref;
 use std::ops::DerefMut;
+use std::path::Path;
 use std::sync::Arc;
 
 /// A design unit with design unit data
@@ -293,6 +296,10 @@ pub struct DesignRoot {
     pub(super) std_ulogic: Option<EntityId>,
     libraries: FnvHashMap<Symbol, Library>,
 
+    // Packages whose declarations are implicitly visible like those of STD.STANDARD
+    // library name, package name
+    pub(super) predefined_packages: Vec<(Symbol, Symbol)>,
+
     // Arena storage of all declaration in the design
     pub(super) arenas: FinalArena,
 
@@ -320,6 +327,7 @@ impl DesignRoot {
             symbols,
             arenas: FinalArena::default(),
             libraries: FnvHashMap::default(),
+            predefined_packages: Vec::new(),
             users_of: RwLock::new(FnvHashMap::default()),
             missing_unit: RwLock::new(FnvHashMap::default()),
             users_of_library_all: RwLock::new(FnvHashMap::default()),
@@ -394,6 +402,15 @@ impl DesignRoot {
             .map(|library| (&library.arena, library.id))
     }
 
+    /// Make all declarations of a package implicitly visible in every design unit,
+    /// such as vendor specific operator overloads that are predefined in a tool.
+    /// Design units in the STD and IEEE libraries and in the library of the package
+    /// itself do not see these declarations since the package may depend on them.
+    /// Must be called before analysis.
+    pub fn add_predefined_package(&mut self, library_name: Symbol, package_name: Symbol) {
+        self.predefined_packages.push((library_name, package_name));
+    }
+
     pub fn add_design_file(&mut self, library_name: Symbol, design_file: DesignFile) {
         self.get_or_create_library(library_name)
             .add_design_file(design_file);
@@ -605,6 +622,258 @@ impl DesignRoot {
         }
     }
 
+    /// Search for all references to the item at the cursor that lie within the same source,
+    /// including its declaration. Used to highlight all occurrences of a symbol in a file.
+    pub fn highlights_at(&self, source: &Source, cursor: Position) -> Vec<SrcPos> {
+        let Some(ent) = self.search_reference(source, cursor) else {
+            return Vec::new();
+        };
+
+        let mut searcher = FindAllReferences::new(self, ent);
+        for unit in self.units_by_source(source) {
+            let _ = unit
+                .unit
+                .expect_analyzed()
+                .search(&unit.tokens, &mut searcher);
+        }
+        searcher.references
+    }
+
+    /// Find the innermost declarative region such as a process, subprogram or
+    /// architecture that contains the cursor
+    pub fn region_at(&self, source: &Source, cursor: Position) -> Option<EntRef<'_>> {
+        let mut searcher = RegionAtCursor::new(self, cursor);
+
+        for unit in self.units_by_source(source) {
+            let _ = unit
+                .unit
+                .expect_analyzed()
+                .search(&unit.tokens, &mut searcher);
+
+            if searcher.result.is_some() {
+                return searcher.result;
+            }
+        }
+
+        None
+    }
+
+    /// Resolve a name such as `work.pkg.func` as if it was written within the given region.
+    /// The name is resolved using the declarations of the region and of every region that
+    /// encloses it, up to and including the primary design unit and its context clauses.
+    pub fn resolve_in_region<'a>(
+        &'a self,
+        region: EntRef<'a>,
+        name: &str,
+    ) -> DiagnosticResult<ResolvedName<'a>> {
+        self.with_parsed_name(name, |stream, mut name| {
+            let arena = Arena::new(ArenaId::default());
+            arena.link(&self.arenas);
+            let (unit_id, scope) = self.scope_of_region(region, &arena, &name.pos)?;
+
+            let context = AnalyzeContext::new(self, &unit_id, &arena, stream);
+
+            let mut diagnostics = Vec::new();
+            match context.name_resolve(&scope, &name.pos, &mut name.item, &mut diagnostics) {
+                Ok(resolved) => Ok(resolved.rebind(self)),
+                Err(_) => Err(diagnostics
+                    .into_iter()
+                    .next()
+                    .unwrap_or_else(|| Diagnostic::error(&name.pos, "Could not resolve name"))),
+            }
+        })
+    }
+
+    /// Every entity that a simple name such as `func` could refer to as if it was written
+    /// within the given region, such as all overloads of a subprogram.
+    /// The visible declarations are the same as for [`DesignRoot::resolve_in_region`].
+    pub fn resolve_all_in_region<'a>(
+        &'a self,
+        region: EntRef<'a>,
+        name: &str,
+    ) -> DiagnosticResult<Vec<EntRef<'a>>> {
+        self.with_parsed_name(name, |_, name| {
+            let Name::Designator(ref designator) = name.item else {
+                return Err(Diagnostic::error(&name.pos, "Expected a simple name"));
+            };
+            let arena = Arena::new(ArenaId::default());
+            arena.link(&self.arenas);
+            let (_, scope) = self.scope_of_region(region, &arena, &name.pos)?;
+            Ok(scope
+                .resolve_all(&name.pos, &designator.item)
+                .into_iter()
+                .map(|ent| self.get_ent(ent.id()))
+                .collect())
+        })
+    }
+
+    fn with_parsed_name<T>(
+        &self,
+        name: &str,
+        fun: impl FnOnce(&TokenStream, WithPos<Name>) -> DiagnosticResult<T>,
+    ) -> DiagnosticResult<T> {
+        let source = Source::inline(Path::new("<name>"), name);
+        let contents = source.contents();
+        let mut diagnostics = Vec::new();
+        let stream = TokenStream::new(
+            Tokenizer::new(&self.symbols, &source, ContentReader::new(&contents)),
+            &mut diagnostics,
+        );
+        if let Some(diagnostic) = diagnostics.into_iter().next() {
+            return Err(diagnostic);
+        }
+
+        let name = parse_name(&stream)?;
+        if let Some(token) = stream.peek() {
+            return Err(Diagnostic::error(token, "Expected end of name"));
+        }
+
+        fun(&stream, name)
+    }
+
+    /// The scope of a region with the declarations and use clauses of the region and of every
+    /// region that encloses it together with the design unit that the region belongs to
+    fn scope_of_region<'a>(
+        &'a self,
+        region: EntRef<'a>,
+        arena: &'a Arena,
+        pos: &SrcPos,
+    ) -> DiagnosticResult<(UnitId, Scope<'a>)> {
+        let chain: Vec<EntRef<'a>> =
+            std::iter::successors(Some(region), |ent| ent.parent).collect();
+        let Some((idx, primary, visibility, primary_region)) =
+            chain.iter().enumerate().find_map(|(idx, ent)| {
+                let ent = match (ent.kind(), ent.related) {
+                    (AnyEntKind::Design(Design::Architecture(primary)), _) => primary.0,
+                    (AnyEntKind::Design(Design::PackageBody), Related::DeclaredBy(primary)) => {
+                        primary
+                    }
+                    _ => ent,
+                };
+                match ent.kind() {
+                    AnyEntKind::Design(
+                        Design::Entity(visibility, region)
+                        | Design::Package(visibility, region)
+                        | Design::UninstPackage(visibility, region),
+                    ) => Some((idx, ent, visibility, region)),
+                    _ => None,
+                }
+            })
+        else {
+            return Err(Diagnostic::error(
+                pos,
+                format!("{} is not within an entity or package", region.describe()),
+            ));
+        };
+
+        let (Some(library_name), Designator::Identifier(primary_name)) =
+            (primary.library_name(), primary.designator())
+        else {
+            return Err(Diagnostic::error(
+                pos,
+                format!("{} is not within a library", region.describe()),
+            ));
+        };
+        let kind = if matches!(primary.kind(), AnyEntKind::Design(Design::Entity(..))) {
+            PrimaryKind::Entity
+        } else {
+            PrimaryKind::Package
+        };
+        let unit_id = UnitId::primary(library_name, kind, primary_name);
+
+        // The regions such as architectures, processes and subprograms between the primary
+        // unit and the given region, where each region is nested within the next
+        let regions: Vec<EntRef<'a>> = chain[..=idx]
+            .iter()
+            .filter(|ent| ent.id() != primary.id())
+            .copied()
+            .collect();
+
+        let mut declarations = FindAllEnt::new(self, |ent: EntRef<'a>| {
+            ent.parent
+                .is_some_and(|parent| regions.iter().any(|region| region.id() == parent.id()))
+        });
+        let mut use_clauses = RegionUseClauses::new(&regions);
+        let mut tokens = None;
+        if let Some(decl_pos) = region.decl_pos() {
+            for unit in self.units_by_source(decl_pos.source()) {
+                let _ = unit
+                    .unit
+                    .expect_analyzed()
+                    .search(&unit.tokens, &mut declarations);
+
+                if tokens.is_none()
+                    && !regions.is_empty()
+                    && matches!(
+                        unit.unit
+                            .expect_analyzed()
+                            .search(&unit.tokens, &mut use_clauses),
+                        Found
+                    )
+                {
+                    tokens = Some(&unit.tokens);
+                }
+            }
+        }
+        let declarations = declarations.result;
+
+        let root_scope = Scope::new(Region::with_visibility(visibility.clone()));
+        let context = tokens.map(|tokens| AnalyzeContext::new(self, &unit_id, arena, tokens));
+        if let Some(ref context) = context {
+            let _ = context.analyze_context_clause(
+                &root_scope,
+                &mut use_clauses.context_clause,
+                &mut NullDiagnostics,
+            );
+        }
+
+        let mut scope = Scope::extend(primary_region, Some(&root_scope));
+        for region in regions.iter().rev() {
+            scope = scope.nested();
+            for ent in declarations
+                .iter()
+                .filter(|ent| ent.parent.is_some_and(|parent| parent.id() == region.id()))
+            {
+                scope.add(ent, &mut NullDiagnostics);
+            }
+            if let Some(ref context) = context {
+                for (_, use_clause) in use_clauses
+                    .use_clauses
+                    .iter_mut()
+                    .filter(|(id, _)| *id == region.id())
+                {
+                    let _ = context.analyze_use_clause(&scope, use_clause, &mut NullDiagnostics);
+                }
+            }
+        }
+
+        Ok((unit_id, scope))
+    }
+
+    /// Classify the token at the cursor. Identifiers that resolve to a named entity
+    /// give the position of its declaration while reserved words and literals,
+    /// which have no declaration, are reported as such.
+    pub fn symbol_under_cursor(&self, source: &Source, cursor: Position) -> SymbolUnderCursor {
+        if let Some(decl_pos) = self
+            .search_reference(source, cursor)
+            .and_then(|ent| ent.decl_pos())
+        {
+            return SymbolUnderCursor::Declaration(decl_pos.clone());
+        }
+
+        let token = self
+            .units_by_source(source)
+            .find_map(|unit| unit.tokens.iter().find(|token| token.pos.contains(cursor)));
+
+        match token.map(|token| token.kind) {
+            Some(
+                Kind::AbstractLiteral | Kind::StringLiteral | Kind::BitString | Kind::Character,
+            ) => SymbolUnderCursor::Literal,
+            Some(kind) if kind.is_reserved_word() => SymbolUnderCursor::Keyword,
+            _ => SymbolUnderCursor::None,
+        }
+    }
+
     #[cfg(test)]
     fn find_std_package(&self, symbol: &str) -> &AnyEnt {
         let std_lib = self.libraries.get(&self.symbol_utf8("std")).unwrap();
@@ -1210,6 +1479,19 @@ fn get_all_affected(
     all_affected
 }
 
+/// What the token at a cursor position refers to
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum SymbolUnderCursor {
+    /// A name with the position of its declaration
+    Declaration(SrcPos),
+    /// A reserved word such as `entity` or `and`
+    Keyword,
+    /// A numeric, string, bit string or character literal
+    Literal,
+    /// Punctuation, unresolved names or no token at all
+    None,
+}
+
 pub struct EntHierarchy<'a> {
     pub ent: EntRef<'a>,
     pub children: Vec<EntHierarchy<'a>>,
@@ -322,6 +322,14 @@ impl<'a> Scope<'a> {
         self.0.as_ref().borrow_mut().lookup(pos, designator)
     }
 
+    /// Every entity the designator could refer to without committing to one of them.
+    /// This is the full overload set for subprograms and the single entity otherwise.
+    pub fn resolve_all(&self, pos: &SrcPos, designator: &Designator) -> Vec<EntRef<'a>> {
+        self.lookup(pos, designator)
+            .map(|names| names.all_entities())
+            .unwrap_or_default()
+    }
+
     /// Used when using context clauses
     pub fn add_context_visibility(&self, visible_pos: Option<&SrcPos>, region: &Region<'a>) {
         self.0
@@ -85,6 +85,15 @@ impl<'a> AnalyzeContext<'a> {
         for choice in choices.iter_mut() {
             match choice.item {
                 Choice::Expression(ref mut expr) => {
+                    if let Some(attr) = find_signal_attribute(expr) {
+                        diagnostics.error(
+                            &attr.pos,
+                            format!(
+                                "Signal attribute '{}' is not static and cannot be used as a choice",
+                                attr.item
+                            ),
+                        );
+                    }
                     if let Some(ttyp) = ttyp {
                         self.expr_pos_with_ttyp(scope, ttyp, &choice.pos, expr, diagnostics)?;
                     } else {
@@ -298,3 +307,41 @@ impl Diagnostic {
         )
     }
 }
+
+/// Find a signal attribute such as 'event within an expression.
+/// Signal attributes are never static.
+fn find_signal_attribute(expr: &Expression) -> Option<&WithPos<AttributeDesignator>> {
+    match expr {
+        Expression::Binary(_, left, right) => {
+            find_signal_attribute(&left.item).or_else(|| find_signal_attribute(&right.item))
+        }
+        Expression::Unary(_, expr) => find_signal_attribute(&expr.item),
+        Expression::Qualified(qexpr) => find_signal_attribute(&qexpr.expr.item),
+        Expression::Name(name) => find_signal_attribute_in_name(name),
+        Expression::Aggregate(_) | Expression::Literal(_) | Expression::New(_) => None,
+    }
+}
+
+fn find_signal_attribute_in_name(name: &Name) -> Option<&WithPos<AttributeDesignator>> {
+    match name {
+        Name::Attribute(attr) => {
+            if matches!(attr.attr.item, AttributeDesignator::Signal(_)) {
+                Some(&attr.attr)
+            } else {
+                find_signal_attribute_in_name(&attr.name.item)
+            }
+        }
+        Name::CallOrIndexed(fcall) => fcall
+            .parameters
+            .iter()
+            .find_map(|assoc| match assoc.actual.item {
+                ActualPart::Expression(ref expr) => find_signal_attribute(expr),
+                ActualPart::Open => None,
+            })
+            .or_else(|| find_signal_attribute_in_name(&fcall.name.item)),
+        Name::Selected(prefix, _) | Name::SelectedAll(prefix) => {
+            find_signal_attribute_in_name(&prefix.item)
+        }
+        Name::Designator(_) | Name::Slice(..) | Name::External(_) => None,
+    }
+}
@@ -4,7 +4,9 @@
 //
 // Copyright (c) 2019, Olof Kraigher olof.kraigher@gmail.com
 
+use super::declarative::ReferenceCollector;
 use super::*;
+use crate::ast::search::Search;
 use crate::ast::*;
 use crate::data::*;
 use crate::named_entity::*;
@@ -143,6 +145,13 @@ impl<'a> AnalyzeContext<'a> {
                 self.sensitivity_list_check(scope, sensitivity_clause, diagnostics)?;
                 if let Some(expr) = condition_clause {
                     self.boolean_expr(scope, expr, diagnostics)?;
+                    if !sensitivity_clause.is_empty() {
+                        self.check_wait_condition_sensitivity(
+                            sensitivity_clause,
+                            expr,
+                            diagnostics,
+                        );
+                    }
                 }
                 if let Some(expr) = timeout_clause {
                     self.expr_with_ttyp(scope, self.time(), expr, diagnostics)?;
@@ -225,7 +234,7 @@ impl<'a> AnalyzeContext<'a> {
             }
             SequentialStatement::Case(ref mut case_stmt) => {
                 let CaseStatement {
-                    is_matching: _,
+                    is_matching,
                     expression,
                     alternatives,
                     end_label_pos: _,
@@ -236,6 +245,16 @@ impl<'a> AnalyzeContext<'a> {
                     self.choice_with_ttyp(scope, ctyp, choices, diagnostics)?;
                     self.analyze_sequential_part(scope, parent, item, diagnostics)?;
                 }
+                if *is_matching {
+                    if let Some(ctyp) = ctyp {
+                        self.check_matching_case_coverage(
+                            ctyp,
+                            expression,
+                            alternatives,
+                            diagnostics,
+                        );
+                    }
+                }
             }
             SequentialStatement::Loop(ref mut loop_stmt) => {
                 let LoopStatement {
@@ -267,12 +286,17 @@ impl<'a> AnalyzeContext<'a> {
                 self.analyze_procedure_call(scope, pcall, diagnostics)?;
             }
             SequentialStatement::SignalAssignment(ref mut assign) => {
-                // @TODO more
-                let SignalAssignment { target, rhs, .. } = assign;
+                check_signal_assignment_allowed(parent, &statement.statement.pos, diagnostics);
+                let SignalAssignment {
+                    target,
+                    delay_mechanism,
+                    rhs,
+                } = assign;
                 self.analyze_waveform_assignment(
                     scope,
                     target,
                     AssignmentType::Signal,
+                    delay_mechanism,
                     rhs,
                     diagnostics,
                 )?;
@@ -288,6 +312,7 @@ impl<'a> AnalyzeContext<'a> {
                 )?;
             }
             SequentialStatement::SignalForceAssignment(ref mut assign) => {
+                check_signal_assignment_allowed(parent, &statement.statement.pos, diagnostics);
                 let SignalForceAssignment {
                     target,
                     force_mode: _,
@@ -302,6 +327,7 @@ impl<'a> AnalyzeContext<'a> {
                 )?;
             }
             SequentialStatement::SignalReleaseAssignment(ref mut assign) => {
+                check_signal_assignment_allowed(parent, &statement.statement.pos, diagnostics);
                 let SignalReleaseAssignment {
                     target,
                     force_mode: _,
@@ -372,6 +398,138 @@ impl<'a> AnalyzeContext<'a> {
 
         Ok(())
     }
+
+    /// Check that the choices of a matching case statement with a std_ulogic selector
+    /// cover all values when there is no others choice. Only the values '0' and '1'
+    /// of each element are considered where '-' within a choice matches both.
+    fn check_matching_case_coverage(
+        &self,
+        ctyp: TypeEnt<'a>,
+        expression: &WithPos<Expression>,
+        alternatives: &[Alternative<Vec<LabeledSequentialStatement>>],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let Some(std_ulogic) = self.root.std_ulogic else {
+            return;
+        };
+
+        let is_scalar = if ctyp.base_type().id() == std_ulogic {
+            true
+        } else if let Some((elem_type, _)) = ctyp.array_type() {
+            if elem_type.base_type().id() != std_ulogic {
+                return;
+            }
+            false
+        } else {
+            return;
+        };
+
+        let mut patterns: Vec<&[u8]> = Vec::new();
+        for choice in alternatives.iter().flat_map(|alt| alt.choices.iter()) {
+            let Choice::Expression(ref expr) = choice.item else {
+                // Others or ranges are not checked
+                return;
+            };
+
+            let pattern = if is_scalar {
+                match expr {
+                    Expression::Literal(Literal::Character(ref chr)) => {
+                        std::slice::from_ref(&chr.item)
+                    }
+                    _ => return,
+                }
+            } else {
+                match expr.as_string_literal() {
+                    Some(string) => string.bytes.as_slice(),
+                    None => return,
+                }
+            };
+            patterns.push(pattern);
+        }
+
+        if let Some(missing) = first_uncovered_value(&patterns) {
+            let missing = if is_scalar {
+                format!("'{missing}'")
+            } else {
+                format!("\"{missing}\"")
+            };
+            diagnostics.error(
+                &expression.pos,
+                format!(
+                    "Choices of matching case statement do not cover all values, missing {missing}"
+                ),
+            );
+        }
+    }
+
+    /// The condition of a wait statement with a sensitivity clause is only evaluated
+    /// when a signal of the sensitivity clause changes.
+    /// Warn about signals of the condition that are not in the sensitivity clause.
+    fn check_wait_condition_sensitivity(
+        &self,
+        sensitivity_clause: &[WithPos<Name>],
+        condition: &WithPos<Expression>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let mut sensitive = ReferenceCollector::default();
+        for name in sensitivity_clause.iter() {
+            let _ = name.search(self.ctx, &mut sensitive);
+        }
+
+        let mut referenced = ReferenceCollector::default();
+        let _ = condition.search(self.ctx, &mut referenced);
+
+        for (id, pos) in referenced.references {
+            if sensitive.contains(id) {
+                continue;
+            }
+            let ent = self.arena.get(id);
+            if let AnyEntKind::Object(Object {
+                class: ObjectClass::Signal,
+                ..
+            }) = ent.kind()
+            {
+                diagnostics.warning(
+                    &pos,
+                    format!(
+                        "Signal '{}' is not in the sensitivity clause of the wait statement",
+                        ent.designator()
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Maximum number of elements of a matching case selector to check coverage of
+const MAX_MATCHING_CASE_WIDTH: usize = 16;
+
+/// Find the first combination of '0' and '1' not matched by any of the patterns
+/// Returns None when all combinations are covered or the patterns have different lengths
+fn first_uncovered_value(patterns: &[&[u8]]) -> Option<String> {
+    let width = patterns.first()?.len();
+    if width == 0
+        || width > MAX_MATCHING_CASE_WIDTH
+        || patterns.iter().any(|pattern| pattern.len() != width)
+    {
+        return None;
+    }
+
+    let matches = |pattern: &[u8], value: usize| {
+        pattern.iter().enumerate().all(|(idx, chr)| {
+            let is_one = (value >> (width - 1 - idx)) & 1 == 1;
+            match chr {
+                b'-' => true,
+                b'1' | b'H' => is_one,
+                b'0' | b'L' => !is_one,
+                _ => false,
+            }
+        })
+    };
+
+    (0..1usize << width)
+        .find(|value| !patterns.iter().any(|pattern| matches(pattern, *value)))
+        .map(|value| format!("{value:0width$b}"))
 }
 
 enum SequentialRoot<'a> {
@@ -380,6 +538,17 @@ enum SequentialRoot<'a> {
     Function(TypeEnt<'a>),
 }
 
+// Functions cannot have side effects through signals
+fn check_signal_assignment_allowed(
+    parent: EntRef,
+    pos: &SrcPos,
+    diagnostics: &mut dyn DiagnosticHandler,
+) {
+    if let SequentialRoot::Function(_) = SequentialRoot::from(parent) {
+        diagnostics.error(pos, "Signal assignment is not allowed within a function");
+    }
+}
+
 fn find_outer_loop(ent: EntRef, label: Option<&Symbol>) -> bool {
     match ent.kind() {
         AnyEntKind::Sequential(Some(Sequential::Loop)) => {
@@ -220,7 +220,7 @@ impl<'a> AnalyzeContext<'a> {
         self.arena.get_type(self.standard_types().time)
     }
 
-    fn file_open_kind(&self) -> TypeEnt<'a> {
+    pub(crate) fn file_open_kind(&self) -> TypeEnt<'a> {
         self.arena.get_type(self.standard_types().file_open_kind)
     }
 
@@ -24,12 +24,224 @@ impl<'a> AnalyzeContext<'a> {
                 self.resolve_target_name(scope, name, &target.pos, assignment_type, diagnostics)
             }
             Target::Aggregate(ref mut assocs) => {
-                self.analyze_aggregate(scope, assocs, diagnostics)?;
+                self.resolve_aggregate_target(scope, assocs, assignment_type, diagnostics)?;
                 Err(EvalError::Unknown)
             }
         }
     }
 
+    /// Resolve each element of an aggregate target such as `(a, b) <= c`
+    fn resolve_aggregate_target(
+        &self,
+        scope: &Scope<'a>,
+        assocs: &mut [ElementAssociation],
+        assignment_type: AssignmentType,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        for assoc in assocs.iter_mut() {
+            let expr = match assoc {
+                ElementAssociation::Positional(expr) => expr,
+                ElementAssociation::Named(choices, expr) => {
+                    self.choice_with_ttyp(scope, None, choices, diagnostics)?;
+                    expr
+                }
+            };
+
+            match expr.item {
+                Expression::Name(ref mut name) => {
+                    as_fatal(self.resolve_target_name(
+                        scope,
+                        name,
+                        &expr.pos,
+                        assignment_type,
+                        diagnostics,
+                    ))?;
+                }
+                Expression::Aggregate(ref mut assocs) => {
+                    self.resolve_aggregate_target(scope, assocs, assignment_type, diagnostics)?;
+                }
+                _ => {
+                    diagnostics.error(&expr.pos, "Expected name or aggregate as target");
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that the length of the target matches the length of the right hand side
+    /// when both are statically known
+    pub fn check_target_length(
+        &self,
+        scope: &Scope<'a>,
+        target: &mut WithPos<Target>,
+        rhs: &mut WithPos<Expression>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let Some(rhs_length) = self.static_length(scope, rhs)? else {
+            return Ok(());
+        };
+
+        match target.item {
+            Target::Name(ref mut name) => {
+                // The target has already been resolved and diagnosed
+                let resolved =
+                    as_fatal(self.name_resolve(scope, &target.pos, name, &mut NullDiagnostics))?;
+                if let Some(ResolvedName::ObjectName(oname)) = resolved {
+                    if let Some(length) = oname.effective_subtype().length() {
+                        if length != rhs_length {
+                            diagnostics.error(
+                                &target.pos,
+                                format!(
+                                    "Target of length {length} does not match right hand side of length {rhs_length}"
+                                ),
+                            );
+                        }
+                    }
+                }
+                Ok(())
+            }
+            Target::Aggregate(ref assocs) => self.check_aggregate_target_length(
+                scope,
+                &target.pos,
+                assocs,
+                rhs,
+                rhs_length,
+                diagnostics,
+            ),
+        }
+    }
+
+    /// Check that the number of element targets of an aggregate target matches the
+    /// length of the right hand side
+    fn check_aggregate_target_length(
+        &self,
+        scope: &Scope<'a>,
+        target_pos: &SrcPos,
+        assocs: &[ElementAssociation],
+        rhs: &mut WithPos<Expression>,
+        rhs_length: u64,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let rhs_type = match as_fatal(self.expr_unambiguous_type(scope, rhs, &mut NullDiagnostics))?
+        {
+            Some(typ) => typ,
+            None => return Ok(()),
+        };
+
+        let Some((elem_type, _)) = rhs_type.array_type() else {
+            return Ok(());
+        };
+
+        let mut num_elements = 0;
+        for assoc in assocs.iter() {
+            // Named associations and element targets of unknown size such as slices are not checked
+            let ElementAssociation::Positional(ref expr) = assoc else {
+                return Ok(());
+            };
+            let Expression::Name(ref name) = expr.item else {
+                return Ok(());
+            };
+            let Name::Designator(ref designator) = name.as_ref() else {
+                return Ok(());
+            };
+            let Some(obj) = designator
+                .reference
+                .get()
+                .and_then(|id| ObjectEnt::from_any(self.arena.get(id)))
+            else {
+                return Ok(());
+            };
+            if obj.type_mark().base() != elem_type.base() {
+                return Ok(());
+            }
+            num_elements += 1;
+        }
+
+        if num_elements != rhs_length {
+            diagnostics.error(
+                target_pos,
+                format!(
+                    "Aggregate target with {num_elements} elements does not match right hand side of length {rhs_length}"
+                ),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// The length of the right hand side of an assignment when it is statically known
+    fn static_length(
+        &self,
+        scope: &Scope<'a>,
+        expr: &mut WithPos<Expression>,
+    ) -> FatalResult<Option<u64>> {
+        match expr.item {
+            Expression::Qualified(ref qexpr) => Ok(static_qualified_length(qexpr)),
+            Expression::Binary(..) => self.static_concatenation_length(scope, expr),
+            _ => Ok(None),
+        }
+    }
+
+    /// The length of a concatenation such as `a & b & '1'` where all array operands have a
+    /// statically known length
+    fn static_concatenation_length(
+        &self,
+        scope: &Scope<'a>,
+        expr: &mut WithPos<Expression>,
+    ) -> FatalResult<Option<u64>> {
+        let Expression::Binary(ref op, ref mut left, ref mut right) = expr.item else {
+            return Ok(None);
+        };
+        if op.item.item != Operator::Concat {
+            return Ok(None);
+        }
+        let Some(overloaded) = op
+            .item
+            .reference
+            .get()
+            .and_then(|id| OverloadedEnt::from_any(self.arena.get(id)))
+        else {
+            return Ok(None);
+        };
+        let Some(return_type) = overloaded.return_type() else {
+            return Ok(None);
+        };
+
+        let mut length = 0;
+        for (idx, operand) in [left.as_mut(), right.as_mut()].into_iter().enumerate() {
+            // An operand that is not of the array type is a single element
+            if overloaded.nth_base(idx) != Some(return_type.base()) {
+                length += 1;
+                continue;
+            }
+
+            let operand_length = match operand.item {
+                Expression::Literal(Literal::String(ref string)) => Some(string.len() as u64),
+                Expression::Qualified(ref qexpr) => static_qualified_length(qexpr),
+                Expression::Binary(..) => self.static_concatenation_length(scope, operand)?,
+                Expression::Name(ref mut name) => {
+                    match as_fatal(self.name_resolve(
+                        scope,
+                        &operand.pos,
+                        name,
+                        &mut NullDiagnostics,
+                    ))? {
+                        Some(ResolvedName::ObjectName(oname)) => oname.effective_subtype().length(),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            };
+
+            let Some(operand_length) = operand_length else {
+                return Ok(None);
+            };
+            length += operand_length;
+        }
+
+        Ok(Some(length))
+    }
+
     pub fn resolve_target_name(
         &self,
         scope: &Scope<'a>,
@@ -99,3 +311,15 @@ fn is_valid_assignment_type(base: &ObjectBase, assignment_type: AssignmentType)
         }
     }
 }
+
+/// The length of a qualified string literal or positional aggregate such as `bit_vector'("01")`
+fn static_qualified_length(qexpr: &QualifiedExpression) -> Option<u64> {
+    match qexpr.expr.item {
+        Expression::Literal(Literal::String(ref string)) => Some(string.len() as u64),
+        Expression::Aggregate(ref assocs) => assocs
+            .iter()
+            .all(|assoc| matches!(assoc, ElementAssociation::Positional(_)))
+            .then_some(assocs.len() as u64),
+        _ => None,
+    }
+}
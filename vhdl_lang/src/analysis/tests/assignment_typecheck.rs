@@ -173,6 +173,35 @@ end architecture;
     check_diagnostics(diagnostics, expected);
 }
 
+#[test]
+fn generate_parameter_may_not_be_assignment_target() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : natural;
+begin
+  gen: for idx in 0 to 3 generate
+    idx <= 1;
+    sig <= idx;
+  end generate;
+end architecture;
+",
+    );
+
+    let expected = vec![Diagnostic::error(
+        code.s("idx", 2),
+        "loop parameter 'idx' may not be the target of an assignment",
+    )];
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(diagnostics, expected);
+}
+
 #[test]
 fn objects_may_be_assignment_target() {
     let mut builder = LibraryBuilder::new();
@@ -499,6 +528,200 @@ end architecture;
     check_no_diagnostics(&diagnostics);
 }
 
+#[test]
+fn test_slice_and_element_alias() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+    signal data : bit_vector(7 downto 0);
+    constant const : bit_vector(7 downto 0) := (others => '0');
+    alias nibble is data(3 downto 0);
+    alias msb is data(7);
+    alias const_nibble is const(3 downto 0);
+    signal bool : boolean;
+begin
+    nibble <= \"0101\";
+    nibble <= '1';
+    const_nibble <= \"0101\";
+    bool <= msb = '1';
+    bool <= msb;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("nibble <= '1'").s1("'1'"),
+                "character literal does not match array type 'BIT_VECTOR'",
+            ),
+            Diagnostic::error(
+                code.s1("const_nibble <=").s1("const_nibble"),
+                "alias 'const_nibble' of constant may not be the target of an assignment",
+            ),
+            Diagnostic::error(
+                code.s1("bool <= msb;").s1("msb"),
+                "type 'BIT' does not match type 'BOOLEAN'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn typecheck_selected_signal_assignment() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+    signal sel : bit_vector(1 downto 0);
+    signal q, a, b : bit;
+    signal num : natural;
+begin
+    with sel select q <= a when \"00\", b when \"01\", '0' when others;
+    with sel select q <= a when \"00\", num when others;
+    with sel select q <= a when 0, b when others;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("num when others").s1("num"),
+                "signal 'num' of subtype 'NATURAL' does not match type 'BIT'",
+            ),
+            Diagnostic::error(
+                code.s1("when 0").s1("0"),
+                "integer literal does not match array type 'BIT_VECTOR'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn typecheck_conditional_variable_assignment() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+procedure proc(a, b : bit; cond : boolean; num : natural) is
+    variable v : bit;
+begin
+    v := a when cond else b;
+    v := a when cond else num;
+    v := a when num else b;
+end procedure;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("else num").s1("num"),
+                "constant 'num' of subtype 'NATURAL' does not match type 'BIT'",
+            ),
+            Diagnostic::error(
+                code.s1("when num").s1("num"),
+                "subtype 'NATURAL' cannot be implictly converted to type 'BOOLEAN'. Operator ?? is not defined for this type.",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn typecheck_selected_signal_assignment_with_expression_selector() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+    function get_sel return natural is
+    begin
+        return 0;
+    end function;
+
+    signal hi, lo : bit;
+    signal q, a, b : bit;
+begin
+    with get_sel select q <= a when 0, b when others;
+    with get_sel select q <= a when '0', b when others;
+    with hi & lo select q <= a when \"00\", b when others;
+    with hi & lo select q <= a when 0, b when others;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("when '0'").s1("'0'"),
+                "character literal does not match subtype 'NATURAL'",
+            ),
+            Diagnostic::error(
+                code.s1("hi & lo select q <= a when 0").s1("0"),
+                "integer literal does not match array type 'BIT_VECTOR'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn aggregate_target() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+    constant c : bit := '0';
+    signal a, b, d : bit;
+    signal v : bit_vector(1 downto 0);
+begin
+    (a, b) <= v;
+    (a, b) <= bit_vector'(\"01\");
+    (a, b, d) <= bit_vector'(\"01\");
+    (a, c) <= v;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("(a, b, d)"),
+                "Aggregate target with 3 elements does not match right hand side of length 2",
+            ),
+            Diagnostic::error(
+                code.s1("(a, c)").s1("c"),
+                "constant 'c' may not be the target of an assignment",
+            ),
+        ],
+    );
+}
+
 #[test]
 fn assignment_target_all() {
     let mut builder = LibraryBuilder::new();
@@ -621,6 +844,10 @@ end architecture foo;
     check_diagnostics(
         diagnostics,
         vec![
+            Diagnostic::error(
+                code.s1("file c: integer").s1("integer"),
+                "integer type 'INTEGER' is not a file type",
+            ),
             Diagnostic::error(
                 code.s1("proc(d, c, a, b)").s1("d"),
                 "Name must denote a signal name",
@@ -671,3 +898,180 @@ end package body;
     );
     check_no_diagnostics(&builder.analyze())
 }
+
+#[test]
+fn typecheck_reject_inertial_delay() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+    signal q, a : bit;
+begin
+    q <= reject 2 ns inertial a after 5 ns;
+    q <= reject 1 us inertial a after 5 ns;
+    q <= reject 2 inertial a after 5 ns;
+
+    process
+    begin
+        q <= reject 500 ps inertial a after 1 ns, not a after 2 ns;
+        wait;
+    end process;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::warning(
+                code.s1("1 us"),
+                "Reject time is greater than the time of the first waveform element",
+            ),
+            Diagnostic::error(
+                code.s1("reject 2 inertial").s1("2"),
+                "integer literal does not match physical type 'TIME'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn check_length_of_constrained_target() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+procedure proc is
+    variable v : bit_vector(7 downto 0);
+begin
+    v(3 downto 0) := bit_vector'(\"0101\");
+    v(3 downto 0) := bit_vector'(\"01\");
+    v := bit_vector'(\"010\");
+end procedure;
+",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s("v(3 downto 0)", 2),
+                "Target of length 4 does not match right hand side of length 2",
+            ),
+            Diagnostic::error(
+                code.s1("v := ").s1("v"),
+                "Target of length 8 does not match right hand side of length 3",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn check_length_of_concatenation() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+procedure proc is
+    variable v : bit_vector(7 downto 0);
+    variable nibble : bit_vector(3 downto 0);
+    variable b : bit;
+    variable u : bit_vector(0 to 15);
+begin
+    v := nibble & nibble;
+    v := nibble & \"01\" & b & '0';
+    v := u(0 to 3) & bit_vector'(\"0101\");
+    v := nibble & b;
+    v := u(0 to 7) & nibble;
+end procedure;
+",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("v := nibble & b").s1("v"),
+                "Target of length 8 does not match right hand side of length 5",
+            ),
+            Diagnostic::error(
+                code.s1("v := u(0 to 7)").s1("v"),
+                "Target of length 8 does not match right hand side of length 12",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn check_length_of_concatenation_signal_assignment() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+    signal s : bit_vector(7 downto 0);
+    signal nibble : bit_vector(3 downto 0);
+begin
+    s <= nibble & nibble;
+    s <= nibble & nibble(1 downto 0);
+end architecture;
+",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::error(
+            code.s("s <=", 2).s1("s"),
+            "Target of length 8 does not match right hand side of length 6",
+        )],
+    );
+}
+
+#[test]
+fn signal_assignment_in_function() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+signal sig : bit;
+
+function fun return bit is
+  variable var : bit;
+begin
+  var := '1';
+  sig <= var;
+  return var;
+end function;
+",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::error(
+            code.s1("sig <= var;"),
+            "Signal assignment is not allowed within a function",
+        )],
+    );
+}
+
+#[test]
+fn signal_assignment_in_procedure() {
+    let mut builder = LibraryBuilder::new();
+    builder.in_declarative_region(
+        "
+procedure proc(signal sig : out bit) is
+  variable var : bit;
+begin
+  var := '1';
+  sig <= var;
+end procedure;
+",
+    );
+
+    check_no_diagnostics(&builder.analyze());
+}
@@ -567,3 +567,222 @@ end architecture;
         code.s1("prt1").pos()
     );
 }
+
+#[test]
+fn function_conversion_of_port_name_must_accept_port_type() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+    port (
+        theport: out natural
+    );
+end entity;
+
+architecture a of ent is
+begin
+end architecture;
+
+entity ent2 is
+end entity;
+
+architecture a of ent2 is
+    function fun1(arg : real) return natural is
+    begin
+        return 0;
+    end function;
+
+    signal sig : natural;
+begin
+    inst: entity work.ent
+        port map (
+        fun1(theport) => sig);
+end architecture;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("fun1(theport)").s1("fun1"),
+            "No function 'fun1' accepting subtype 'NATURAL'",
+        )],
+    );
+}
+
+#[test]
+fn function_conversion_of_input_port_actual() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+    port (
+        theport: in natural
+    );
+end entity;
+
+architecture a of ent is
+begin
+end architecture;
+
+entity ent2 is
+end entity;
+
+architecture a of ent2 is
+    function fun1(arg : real) return natural is
+    begin
+        return 0;
+    end function;
+
+    signal sig : real;
+begin
+    inst: entity work.ent
+        port map (
+        theport => fun1(sig));
+end architecture;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("fun1", 2).end()),
+        Some(code.s1("fun1").pos())
+    );
+}
+
+#[test]
+fn function_conversion_of_input_port_actual_must_return_port_type() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+    port (
+        theport: in natural
+    );
+end entity;
+
+architecture a of ent is
+begin
+end architecture;
+
+entity ent2 is
+end entity;
+
+architecture a of ent2 is
+    function fun1(arg : real) return real is
+    begin
+        return arg;
+    end function;
+
+    signal sig : real;
+begin
+    inst: entity work.ent
+        port map (
+        theport => fun1(sig));
+end architecture;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("fun1(sig)"),
+            "Expression of real type 'REAL' does not match subtype 'NATURAL'",
+        )],
+    );
+}
+
+#[test]
+fn formal_conversion_is_not_allowed_for_input_port() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+    port (
+        theport: in natural
+    );
+end entity;
+
+architecture a of ent is
+begin
+end architecture;
+
+entity ent2 is
+end entity;
+
+architecture a of ent2 is
+    function fun1(arg : natural) return natural is
+    begin
+        return arg;
+    end function;
+
+    signal sig : natural;
+begin
+    inst: entity work.ent
+        port map (
+        fun1(theport) => sig);
+end architecture;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("fun1(theport)"),
+            "Formal conversion is not allowed for port 'theport' : in",
+        )],
+    );
+}
+
+#[test]
+fn actual_conversion_is_not_allowed_for_output_port() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+    port (
+        theport: out natural
+    );
+end entity;
+
+architecture a of ent is
+begin
+end architecture;
+
+entity ent2 is
+end entity;
+
+architecture a of ent2 is
+    function fun1(arg : natural) return natural is
+    begin
+        return arg;
+    end function;
+
+    signal sig : natural;
+begin
+    inst: entity work.ent
+        port map (
+        theport => fun1(sig));
+end architecture;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("fun1(sig)"),
+            "Actual conversion is not allowed for port 'theport' : out",
+        )],
+    );
+}
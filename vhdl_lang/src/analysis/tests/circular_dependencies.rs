@@ -34,6 +34,43 @@ end context;
     );
 }
 
+#[test]
+fn context_chain_without_cycle() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+package pkg is
+  constant c0 : natural := 0;
+end package;
+
+context ctx3 is
+  library libname;
+  use libname.pkg.all;
+end context;
+
+context ctx2 is
+  library libname;
+  context libname.ctx3;
+end context;
+
+context ctx1 is
+  library libname;
+  context libname.ctx2;
+end context;
+
+library libname;
+context libname.ctx1;
+
+entity ent is
+  generic (g : natural := c0);
+end entity;
+",
+    );
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
 #[test]
 fn use_package() {
     let mut builder = LibraryBuilder::new();
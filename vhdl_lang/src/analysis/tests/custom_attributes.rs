@@ -219,6 +219,40 @@ end architecture;
     );
 }
 
+#[test]
+fn variable_with_signal_entity_class() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+    attribute myattr : boolean;
+
+    signal good : natural;
+    attribute myattr of good : signal is true;
+begin
+    process
+        variable bad : natural;
+        attribute myattr of bad : signal is true;
+    begin
+    end process;
+end architecture;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s("bad", 2),
+            "variable 'bad' is not of class signal",
+        )],
+    );
+}
+
 #[test]
 fn subtype_entity_class() {
     let mut builder = LibraryBuilder::new();
@@ -319,3 +353,27 @@ end architecture;
         )],
     );
 }
+
+#[test]
+fn enum_encoding_attribute_specification() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+attribute enum_encoding : string;
+type state_t is (idle, busy, done);
+attribute enum_encoding of state_t : type is \"00 01 10\";
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("enum_encoding of").start()),
+        Some(code.s1("enum_encoding").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("state_t :").start()),
+        Some(code.s1("state_t").pos())
+    );
+}
@@ -3,7 +3,7 @@
 // You can obtain one at http://mozilla.org/MPL/2.0/.
 //
 // Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
-use crate::analysis::tests::{check_diagnostics, LibraryBuilder};
+use crate::analysis::tests::{check_diagnostics, check_no_diagnostics, LibraryBuilder};
 use crate::Diagnostic;
 
 #[test]
@@ -47,6 +47,165 @@ end architecture;
     )
 }
 
+#[test]
+pub fn non_shared_variable_not_allowed_in_package() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "\
+package pkg is
+    variable v : natural;
+    shared variable sv : natural;
+    signal s : bit;
+end package;
+    ",
+    );
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::error(
+            code.s1("variable v : natural;"),
+            "variable declaration not allowed here",
+        )],
+    )
+}
+
+#[test]
+pub fn unconstrained_signal_declaration() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "\
+entity ent is
+    port (
+        p : in bit_vector
+    );
+end entity;
+
+architecture a of ent is
+    signal s : bit_vector;
+    signal s_ok : bit_vector(0 to 7);
+    constant c : bit_vector := \"0101\";
+begin
+    process
+        variable v : string;
+    begin
+    end process;
+end architecture;
+    ",
+    );
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("s : bit_vector").s1("bit_vector"),
+                "Unconstrained array type 'BIT_VECTOR' is not allowed in a signal declaration",
+            ),
+            Diagnostic::error(
+                code.s1("v : string").s1("string"),
+                "Unconstrained array type 'STRING' is not allowed in a variable declaration",
+            ),
+        ],
+    )
+}
+
+#[test]
+pub fn unconstrained_subtype_in_signal_declaration() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_std_logic_1164();
+    let code = builder.code(
+        "libname",
+        "\
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity ent is
+    port (
+        p : in std_logic_vector
+    );
+end entity;
+
+architecture a of ent is
+    subtype u_t is bit_vector;
+    subtype c_t is u_t(0 to 3);
+    signal s : std_logic_vector;
+    signal s_ok : std_logic_vector(7 downto 0);
+    signal u : u_t;
+    signal c : c_t;
+begin
+end architecture;
+    ",
+    );
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("s : std_logic_vector").s1("std_logic_vector"),
+                "Unconstrained subtype 'STD_LOGIC_VECTOR' is not allowed in a signal declaration",
+            ),
+            Diagnostic::error(
+                code.s1("u : u_t").s1("u_t"),
+                "Unconstrained subtype 'u_t' is not allowed in a signal declaration",
+            ),
+        ],
+    )
+}
+
+#[test]
+pub fn constant_depending_on_itself_through_function() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "\
+package pkg is
+end package;
+
+package body pkg is
+    function get_value return natural;
+    constant c : natural := get_value;
+
+    function get_value return natural is
+    begin
+        return c + 1;
+    end function;
+end package body;
+    ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("c : natural").s1("c"),
+            "Constant 'c' depends on its own value through a call to 'get_value'",
+        )
+        .related(code.s("get_value", 3), "Defined here")],
+    )
+}
+
+#[test]
+pub fn recursive_function_in_constant_is_allowed() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "\
+package pkg is
+end package;
+
+package body pkg is
+    function factorial(n : natural) return natural is
+    begin
+        if n = 0 then
+            return 1;
+        end if;
+        return n * factorial(n - 1);
+    end function;
+
+    constant c : natural := factorial(3);
+end package body;
+    ",
+    );
+    check_no_diagnostics(&builder.analyze());
+}
+
 // Issue #242
 #[test]
 pub fn attribute_with_wrong_type() {
@@ -95,3 +254,209 @@ end entity test;
         )],
     )
 }
+
+#[test]
+pub fn configuration_specification_binds_matching_component() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "\
+entity ent is
+    generic (width : natural);
+    port (clk : in bit; data : in bit_vector(width - 1 downto 0));
+end entity;
+
+entity top is
+end entity;
+
+architecture a of top is
+    component ent is
+        generic (width : natural);
+        port (clk : in bit; data : in bit_vector(width - 1 downto 0));
+    end component;
+
+    for all : ent use entity work.ent;
+begin
+end architecture;
+    ",
+    );
+    check_diagnostics(builder.analyze(), vec![])
+}
+
+#[test]
+pub fn configuration_specification_with_mismatched_component() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "\
+entity ent is
+    port (clk : in bit; data : in integer);
+end entity;
+
+entity top is
+end entity;
+
+architecture a of top is
+    component comp is
+        port (clk : in bit; data : in bit; valid : in bit);
+    end component;
+
+    component comp2 is
+        port (clk : in bit; data : in bit);
+    end component;
+
+    for all : comp use entity work.ent;
+    for all : comp2 use entity work.ent port map (clk => clk, data => open);
+begin
+end architecture;
+    ",
+    );
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("work.ent;").s1("work.ent"),
+                "port 'data' of component 'comp' does not match integer type 'INTEGER' of entity 'ent'",
+            )
+            .related(
+                code.s1("data : in bit;").s1("data"),
+                "Declared with type 'BIT'",
+            ),
+            Diagnostic::error(
+                code.s1("work.ent;").s1("work.ent"),
+                "No port 'valid' within entity 'ent' to bind component 'comp' to",
+            )
+            .related(code.s1("valid"), "Defined here"),
+        ],
+    )
+}
+
+#[test]
+pub fn generic_default_may_only_reference_earlier_generics() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "\
+entity ent is
+    generic (
+        a : integer := 1;
+        b : integer := a;
+        c : integer := d;
+        d : integer := 2
+    );
+end entity;
+    ",
+    );
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::error(
+            code.s1(":= d").s1("d"),
+            "No declaration of 'd'",
+        )],
+    )
+}
+
+#[test]
+pub fn resolved_and_constrained_subtype() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_std_logic_1164();
+    let code = builder.code(
+        "libname",
+        "\
+library ieee;
+use ieee.std_logic_1164.all;
+
+package pkg is
+    function resolve_int(values : integer_vector) return integer;
+
+    subtype rslv is (resolved) std_ulogic_vector(7 downto 0);
+    subtype rint is resolve_int integer range 0 to 7;
+
+    signal s : rslv;
+    signal i : rint;
+end package;
+    ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("resolve_int integer").start()),
+        Some(code.s1("resolve_int").pos())
+    );
+}
+
+#[test]
+pub fn resolution_function_with_bad_signature() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_std_logic_1164();
+    let code = builder.code(
+        "libname",
+        "\
+library ieee;
+use ieee.std_logic_1164.all;
+
+package pkg is
+    function first(values : bit_vector) return integer;
+
+    subtype rslv is resolved std_logic_vector(7 downto 0);
+    subtype rint is first integer range 0 to 7;
+end package;
+    ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("resolved std_logic_vector").s1("resolved"),
+                "'resolved' is not a resolution function of subtype 'STD_LOGIC_VECTOR'",
+            ),
+            Diagnostic::error(
+                code.s1("first integer").s1("first"),
+                "'first' is not a resolution function of integer type 'INTEGER'",
+            ),
+        ],
+    )
+}
+
+#[test]
+pub fn empty_enumeration_and_record_types() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type e is ();
+type r is record
+end record;
+    ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("e is").s1("e"),
+                "Enumeration type 'e' must have at least one literal",
+            ),
+            Diagnostic::error(
+                code.s1("r is").s1("r"),
+                "Record type 'r' must have at least one element",
+            ),
+        ],
+    )
+}
+
+#[test]
+pub fn non_empty_enumeration_and_record_types() {
+    let mut builder = LibraryBuilder::new();
+    builder.in_declarative_region(
+        "
+type e is (a);
+type r is record
+    f : e;
+end record;
+    ",
+    );
+
+    check_no_diagnostics(&builder.analyze());
+}
@@ -0,0 +1,98 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+
+#[test]
+fn disconnection_specification_resolves_signal() {
+    check_search_reference(
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+    signal decl : bit;
+    disconnect decl : bit after 10 ns;
+begin
+end architecture;
+",
+    );
+}
+
+#[test]
+fn disconnection_specification_with_others_and_all() {
+    check_code_with_no_diagnostics(
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+    constant delay : time := 5 ns;
+    disconnect others : bit after delay;
+    disconnect all : natural after 1 ns;
+begin
+end architecture;
+",
+    );
+}
+
+#[test]
+fn disconnection_specification_delay_must_be_time() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+    signal sig : bit;
+    disconnect sig : bit after 10;
+begin
+end architecture;
+",
+    );
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::error(
+            code.s1("after 10").s1("10"),
+            "integer literal does not match physical type 'TIME'",
+        )],
+    );
+}
+
+#[test]
+fn disconnection_specification_must_name_signal_of_type_mark() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+    signal sig : bit;
+    constant c0 : bit := '0';
+    disconnect sig : boolean after 10 ns;
+    disconnect c0 : bit after 10 ns;
+begin
+end architecture;
+",
+    );
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("disconnect sig").s1("sig"),
+                "signal 'sig' of type 'BIT' does not match type 'BOOLEAN'",
+            ),
+            Diagnostic::error(
+                code.s1("disconnect c0").s1("c0"),
+                "constant 'c0' is not a signal",
+            ),
+        ],
+    );
+}
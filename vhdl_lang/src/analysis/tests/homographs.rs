@@ -1058,3 +1058,79 @@ end architecture;
     ",
     );
 }
+
+#[test]
+fn forbid_duplicate_architectures_in_different_files() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+entity ent is
+end entity;",
+    );
+
+    let arch1 = builder.code(
+        "libname",
+        "
+architecture rtl of ent is
+begin
+end architecture;",
+    );
+
+    let arch2 = builder.code(
+        "libname",
+        "
+architecture RTL of ENT is
+begin
+end architecture;",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::error(
+            arch2.s1("RTL"),
+            "Duplicate architecture 'RTL' of entity 'ENT'",
+        )
+        .related(arch1.s1("rtl"), "Previously defined here")],
+    );
+}
+
+#[test]
+fn forbid_homographs_within_interface_identifier_list() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+generic (
+g1, g2, g1 : natural
+);
+port (
+p1, p2 : bit;
+p1 : bit;
+g2 : bit
+);
+end entity;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(diagnostics, duplicates(&code, &["g1", "p1", "g2"]));
+}
+
+#[test]
+fn allows_distinct_interface_names() {
+    check_code_with_no_diagnostics(
+        "
+entity ent is
+generic (
+a1, a2 : natural
+);
+port (
+b1, b2 : bit;
+b3 : bit
+);
+end entity;
+",
+    );
+}
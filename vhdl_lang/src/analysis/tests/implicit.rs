@@ -46,6 +46,60 @@ end package body;
     );
 }
 
+#[test]
+fn file_declarations_with_open_information() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+use std.textio.all;
+
+package pkg is
+end package;
+
+package body pkg is
+  type text_file is file of string;
+
+  procedure proc is
+    file f1 : text open read_mode is \"x.txt\";
+    file f2 : text_file;
+    file bad1 : text open 0 is \"x.txt\";
+    file bad2 : text open write_mode is 'c';
+    file bad3 : integer;
+  begin
+    file_open(f2, \"y.txt\", write_mode);
+    write(f2, \"hello\");
+    file_close(f2);
+  end procedure;
+end package body;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("open 0").s1("0"),
+                "integer literal does not match type 'FILE_OPEN_KIND'",
+            ),
+            Diagnostic::error(
+                code.s1("'c'"),
+                "character literal does not match array type 'STRING'",
+            ),
+            Diagnostic::error(
+                code.s1("bad3 : integer").s1("integer"),
+                "integer type 'INTEGER' is not a file type",
+            ),
+        ],
+    );
+
+    let file_open = root
+        .search_reference(code.source(), code.s1("file_open").start())
+        .unwrap();
+    assert_eq!(file_open.designator().to_string(), "FILE_OPEN");
+}
+
 #[test]
 fn adds_to_string_for_integer_types() {
     check_code_with_no_diagnostics(
@@ -281,3 +335,38 @@ end package;
 ",
     );
 }
+
+#[test]
+fn resolves_operators_of_predefined_package() {
+    let mut builder = LibraryBuilder::new();
+    let vendor = builder.code(
+        "vendor",
+        "
+package operators is
+    function \"+\"(left : bit; right : integer) return bit;
+end package;
+",
+    );
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+    signal s : bit;
+    constant c : bit := s + 1;
+begin
+end architecture;
+",
+    );
+    builder.add_predefined_package("vendor", "operators");
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("s + 1").s1("+").start()),
+        Some(vendor.s1("\"+\"").pos())
+    );
+}
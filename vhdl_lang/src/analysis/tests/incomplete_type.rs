@@ -4,12 +4,6 @@
 //
 // Copyright (c) 2019, Olof Kraigher olof.kraigher@gmail.com
 
-// @TODO
-// 5.4.2 Incomplete type declarations
-// Prior to the end of the corresponding full type declaration, the only allowed use of a name that denotes a type
-// declared by an incomplete type declaration is as the type mark in the subtype indication of an access type
-// definition; no constraints are allowed in this subtype indication.
-
 use super::*;
 use crate::data::SrcPos;
 
@@ -22,6 +16,7 @@ fn allows_incomplete_type_definition() {
 package pkg is
   type rec_t;
   type rec_t is record
+    f : bit;
   end record;
 
   type enum_t;
@@ -44,6 +39,7 @@ package pkg is
 type rec_t;
 type rec_t;
 type rec_t is record
+  f : bit;
 end record;
 end package;
 ",
@@ -66,6 +62,7 @@ end package;
 package body pkg is
 -- Must appear in the same immediate declarative region
 type rec_t is record
+  f : bit;
 end record;
 end package body;
 ",
@@ -83,6 +80,7 @@ begin
 blk : block
 -- Must appear in the same immediate declarative region
 type rec_t is record
+  f : bit;
 end record;
 begin
 end block;
@@ -168,6 +166,61 @@ end package;
     check_diagnostics(diagnostics, vec![missing_full_error(&code.s1("rec_t"))]);
 }
 
+#[test]
+fn allows_access_to_incomplete_type_before_full_definition() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+package pkg is
+  type node_t;
+  type node_ptr_t is access node_t;
+  type node_t is record
+     value: natural;
+     next_node: node_ptr_t;
+  end record;
+  signal node : node_t;
+end package;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn error_on_use_of_incomplete_type_before_full_definition() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  type rec_t;
+  type arr_t is array (natural range <>) of rec_t;
+  subtype sub_t is rec_t;
+  type rec_t is record
+    f : bit;
+  end record;
+end package;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s("rec_t", 2),
+                "Incomplete type 'rec_t' cannot be used before its full type declaration",
+            ),
+            Diagnostic::error(
+                code.s("rec_t", 3),
+                "Incomplete type 'rec_t' cannot be used before its full type declaration",
+            ),
+        ],
+    );
+}
+
 fn missing_full_error(pos: &impl AsRef<SrcPos>) -> Diagnostic {
     let mut error = Diagnostic::error(
         pos,
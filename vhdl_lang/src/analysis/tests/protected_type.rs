@@ -336,3 +336,58 @@ end package body;
         2
     );
 }
+
+#[test]
+fn shared_protected_variable_used_from_multiple_processes() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  type counter_t is protected
+    procedure increment;
+    impure function value return natural;
+  end protected;
+
+  type counter_t is protected body
+    variable count : natural := 0;
+
+    procedure increment is
+    begin
+      count := count + 1;
+    end procedure;
+
+    impure function value return natural is
+    begin
+      return count;
+    end function;
+  end protected body;
+
+  shared variable counter : counter_t;
+begin
+  p0 : process
+  begin
+    counter.increment;
+    wait;
+  end process;
+
+  p1 : process
+    variable v : natural;
+  begin
+    counter.increment;
+    v := counter.value;
+    wait;
+  end process;
+end architecture;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let increment = code.s1("procedure increment;").s1("increment");
+    assert_eq!(root.find_all_references_pos(&increment.pos()).len(), 4);
+}
@@ -85,6 +85,69 @@ end configuration;
     check_no_diagnostics(&builder.analyze());
 }
 
+#[test]
+fn warns_on_component_configuration_without_instances() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity leaf is
+end entity;
+
+entity ent is
+end entity;
+
+architecture rtl of ent is
+  component leaf is
+  end component;
+  component unused is
+  end component;
+begin
+  inst1 : leaf;
+  inst2 : component leaf;
+end architecture;
+
+configuration cfg_good of ent is
+  for rtl
+    for inst1 : leaf
+      use entity work.leaf;
+    end for;
+    for others : leaf
+      use entity work.leaf;
+    end for;
+  end for;
+end configuration;
+
+configuration cfg_bad of ent is
+  for rtl
+    for inst1, inst2 : leaf
+      use entity work.leaf;
+    end for;
+    for others : leaf
+      use entity work.leaf;
+    end for;
+    for all : unused
+    end for;
+  end for;
+end configuration;
+",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::warning(
+                code.s("others : leaf", 2).s1("leaf"),
+                "'others' does not match any instance of component 'leaf'",
+            ),
+            Diagnostic::warning(
+                code.s1("all : unused").s1("unused"),
+                "'all' does not match any instance of component 'unused'",
+            ),
+        ],
+    );
+}
+
 #[test]
 fn error_on_configuration_of_entity_outside_of_library() {
     let mut builder = LibraryBuilder::new();
@@ -201,6 +264,47 @@ end package body;
     );
 }
 
+#[test]
+fn error_on_body_of_non_package() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+package body ent is
+end package body;
+",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::error(
+            code.s1("package body ent").s1("ent"),
+            "No package 'ent' for package body within library 'libname'",
+        )
+        .related(code.s1("entity ent").s("ent", 2), "Found entity 'ent'")],
+    );
+}
+
+#[test]
+fn package_body_with_package() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+package pkg is
+end package;
+
+package body pkg is
+end package body;
+",
+    );
+
+    check_no_diagnostics(&builder.analyze());
+}
+
 #[test]
 fn error_on_package_body_before_package_in_same_file() {
     let mut builder = LibraryBuilder::new();
@@ -5,6 +5,9 @@
 // Copyright (c) 2019, Olof Kraigher olof.kraigher@gmail.com
 
 use super::*;
+use crate::analysis::{ResolvedName, SymbolUnderCursor};
+use crate::data::DiagnosticResult;
+use crate::syntax::test::assert_eq_unordered;
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -684,7 +687,7 @@ architecture a of ent is
 begin
   main : process is
   begin
-   (0 => decl) := (0 => 1);
+   (0 => decl) <= (0 => 1);
   end process;
 end architecture;
 ",
@@ -1304,6 +1307,32 @@ end architecture;",
     );
 }
 
+#[test]
+fn attribute_of_constrained_record_field() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type rec_t is record
+  field : bit_vector(7 downto 0);
+end record;
+
+signal rec : rec_t;
+constant len : natural := rec.field'length;
+constant high : natural := rec.field'high;
+constant bad : boolean := rec.field'length;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s("rec.field'length", 2),
+            "Expression of type universal_integer does not match type 'BOOLEAN'",
+        )],
+    );
+}
+
 #[test]
 fn record_subtype_can_be_selected() {
     let mut builder = LibraryBuilder::new();
@@ -1431,6 +1460,33 @@ end architecture;
     );
 }
 
+#[test]
+fn scalar_object_cannot_be_selected() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end;
+
+architecture a of ent is
+  signal int_signal, s : integer;
+begin
+  s <= int_signal.foo;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("int_signal.foo").s1("int_signal"),
+            "Integer type 'INTEGER' may not be the prefix of a selected name",
+        )],
+    );
+}
+
 #[test]
 fn incomplete_access_type_of_record_can_be_selected() {
     let mut builder = LibraryBuilder::new();
@@ -2105,7 +2161,7 @@ package pkg2 is
 end package pkg2;
  
 package pkg3 is
-    variable v1 : work.pkg2.pkg1.type1_t;
+    shared variable v1 : work.pkg2.pkg1.type1_t(0 to 1);
 end package;",
     );
     let (_root, diagnostics) = builder.get_analyzed_root();
@@ -2131,9 +2187,642 @@ end package pkg2;
 
 package pkg3 is
     package pkg2 is new work.pkg2 generic map (1);
-    variable v1 : pkg2.pkg1.type1_t;
+    shared variable v1 : pkg2.pkg1.type1_t(0 to 1);
 end package;",
     );
     let (_root, diagnostics) = builder.get_analyzed_root();
     check_no_diagnostics(&diagnostics);
 }
+
+#[test]
+pub fn guarded_block_declares_implicit_guard_signal() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : bit;
+  signal b : boolean;
+begin
+  blk : block (sig = '1')
+  begin
+    b <= GUARD;
+  end block;
+
+  b <= guard;
+end architecture;
+",
+    );
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_diagnostics(diagnostics, vec![missing(&code, "guard", 1)]);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("GUARD").start()),
+        Some(code.s1("sig = '1'").pos())
+    );
+}
+
+#[test]
+pub fn guard_expression_must_be_boolean() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : natural;
+begin
+  blk : block (sig)
+  begin
+  end block;
+end architecture;
+",
+    );
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::error(
+            code.s1("(sig)").s1("sig"),
+            "subtype 'NATURAL' cannot be implictly converted to type 'BOOLEAN'. Operator ?? is not defined for this type.",
+        )],
+    );
+}
+
+#[test]
+fn resolves_procedures_of_std_env() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+use std.env.finish;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  constant limit : delay_length := std.env.resolution_limit;
+begin
+  main : process
+  begin
+    std.env.stop(1);
+    finish;
+  end process;
+end architecture;",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    // The first occurrence of finish is the use clause
+    for (name, occurrence) in [("finish", 2), ("stop", 1), ("resolution_limit", 1)] {
+        let ent = root
+            .search_reference(code.source(), code.s(name, occurrence).start())
+            .unwrap_or_else(|| panic!("{name} was not resolved"));
+        assert!(ent
+            .decl_pos()
+            .unwrap()
+            .source
+            .file_name()
+            .ends_with("env.vhd"));
+    }
+}
+
+#[test]
+fn highlights_all_occurrences_within_file() {
+    let mut builder = LibraryBuilder::new();
+    let pkg = builder.code(
+        "libname",
+        "
+package pkg is
+  signal shared_sig : natural;
+end package;",
+    );
+
+    let code = builder.code(
+        "libname",
+        "
+use work.pkg.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : natural;
+  signal other : natural;
+begin
+  sig <= other;
+  other <= sig + shared_sig;
+  main : process
+  begin
+    wait on sig, shared_sig;
+  end process;
+end architecture;",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq_unordered(
+        &root.highlights_at(code.source(), code.s1("sig <= other").start()),
+        &[
+            code.s1("signal sig").s("sig", 2).pos(),
+            code.s1("sig <= other").s1("sig").pos(),
+            code.s1("<= sig +").s1("sig").pos(),
+            code.s1("wait on sig").s1("sig").pos(),
+        ],
+    );
+
+    // The declaration in the other file is not included
+    assert_eq_unordered(
+        &root.highlights_at(code.source(), code.s1("shared_sig").start()),
+        &[code.s("shared_sig", 1).pos(), code.s("shared_sig", 2).pos()],
+    );
+    assert_eq_unordered(
+        &root.highlights_at(pkg.source(), pkg.s1("shared_sig").start()),
+        &[pkg.s1("shared_sig").pos()],
+    );
+}
+
+#[test]
+fn symbol_under_cursor_classifies_tokens() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  constant c0 : natural := 16;
+  constant c1 : natural := c0 + 1;
+end package;",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let source = code.source();
+    assert_eq!(
+        root.symbol_under_cursor(source, code.s1("c0 +").start()),
+        SymbolUnderCursor::Declaration(code.s1("c0").pos())
+    );
+    assert_eq!(
+        root.symbol_under_cursor(source, code.s1("package").start()),
+        SymbolUnderCursor::Keyword
+    );
+    assert_eq!(
+        root.symbol_under_cursor(source, code.s1("constant").start()),
+        SymbolUnderCursor::Keyword
+    );
+    assert_eq!(
+        root.symbol_under_cursor(source, code.s1("16").start()),
+        SymbolUnderCursor::Literal
+    );
+    assert_eq!(
+        root.symbol_under_cursor(source, code.s1(":").start()),
+        SymbolUnderCursor::None
+    );
+}
+
+#[test]
+fn region_at_cursor_is_innermost_region() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : natural;
+begin
+  main : process
+    variable var : natural;
+  begin
+    var := sig;
+    wait;
+  end process;
+end architecture;",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let region = root
+        .region_at(code.source(), code.s1("var := sig").start())
+        .unwrap();
+    assert_eq!(region.decl_pos(), Some(&code.s1("main").pos()));
+
+    let region = root
+        .region_at(code.source(), code.s1("signal sig").start())
+        .unwrap();
+    assert_eq!(
+        region.decl_pos(),
+        Some(&code.s1("architecture a").s("a", 2).pos())
+    );
+
+    assert!(root
+        .region_at(code.source(), Position::new(100, 0))
+        .is_none());
+}
+
+#[test]
+fn resolve_name_in_region() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  function func return natural;
+end package;
+
+use work.pkg.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : natural;
+begin
+  main : process
+    variable var : natural;
+  begin
+    wait;
+  end process;
+end architecture;
+
+entity other is
+end entity;",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let func_pos = |resolved: DiagnosticResult<ResolvedName>| match resolved {
+        Ok(ResolvedName::Overloaded(_, overloaded)) => overloaded
+            .entities()
+            .next()
+            .and_then(|ent| ent.decl_pos().cloned()),
+        other => panic!("Expected overloaded name, got {other:?}"),
+    };
+
+    let region = root
+        .region_at(code.source(), code.s1("wait").start())
+        .unwrap();
+    assert_eq!(
+        func_pos(root.resolve_in_region(region, "work.pkg.func")),
+        Some(code.s1("func return").s1("func").pos())
+    );
+    // Visible through the use clause of the entity
+    assert_eq!(
+        func_pos(root.resolve_in_region(region, "func")),
+        Some(code.s1("func return").s1("func").pos())
+    );
+
+    let other = root
+        .search_reference(code.source(), code.s1("other").start())
+        .unwrap();
+    assert_eq!(
+        func_pos(root.resolve_in_region(other, "work.pkg.func")),
+        Some(code.s1("func return").s1("func").pos())
+    );
+    assert!(root.resolve_in_region(other, "func").is_err());
+    assert!(root.resolve_in_region(region, "work.pkg.").is_err());
+    assert!(root.resolve_in_region(region, "func extra").is_err());
+}
+
+#[test]
+fn resolve_all_overloads_in_region() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  function func return natural;
+  function func(arg : natural) return natural;
+end package;
+
+use work.pkg.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : natural;
+begin
+end architecture;",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let decl_pos = |resolved: DiagnosticResult<Vec<EntRef>>| {
+        resolved
+            .unwrap()
+            .into_iter()
+            .map(|ent| ent.decl_pos().cloned())
+            .collect::<Vec<_>>()
+    };
+
+    let architecture = root
+        .region_at(code.source(), code.s1("signal").start())
+        .unwrap();
+    assert_eq!(
+        decl_pos(root.resolve_all_in_region(architecture, "func")),
+        vec![
+            Some(code.s1("func return").s1("func").pos()),
+            Some(code.s1("func(arg").s1("func").pos())
+        ]
+    );
+    assert_eq!(
+        decl_pos(root.resolve_all_in_region(architecture, "sig")),
+        vec![Some(code.s1("sig :").s1("sig").pos())]
+    );
+    assert!(root
+        .resolve_all_in_region(architecture, "missing")
+        .unwrap()
+        .is_empty());
+    assert!(root
+        .resolve_all_in_region(architecture, "work.pkg.func")
+        .is_err());
+}
+
+#[test]
+fn resolve_declarations_of_enclosing_regions() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+  port (clk : in bit);
+end entity;
+
+architecture a of ent is
+  signal sig : natural;
+begin
+  main : process
+    variable var : natural;
+  begin
+    wait;
+  end process;
+end architecture;",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let decl_pos = |resolved: DiagnosticResult<ResolvedName>| resolved.unwrap().decl_pos().cloned();
+
+    let process = root
+        .region_at(code.source(), code.s1("wait").start())
+        .unwrap();
+    assert_eq!(
+        decl_pos(root.resolve_in_region(process, "var")),
+        Some(code.s1("var :").s1("var").pos())
+    );
+    assert_eq!(
+        decl_pos(root.resolve_in_region(process, "sig")),
+        Some(code.s1("sig :").s1("sig").pos())
+    );
+    assert_eq!(
+        decl_pos(root.resolve_in_region(process, "clk")),
+        Some(code.s1("clk").pos())
+    );
+
+    let architecture = process.parent.unwrap();
+    assert_eq!(
+        decl_pos(root.resolve_in_region(architecture, "sig")),
+        Some(code.s1("sig :").s1("sig").pos())
+    );
+    assert!(root.resolve_in_region(architecture, "var").is_err());
+}
+
+#[test]
+fn resolve_name_made_visible_by_use_clauses_of_regions() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  function func return natural;
+end package;
+
+package pkg2 is
+  constant c : natural := 0;
+end package;
+
+entity ent is
+end entity;
+
+use work.pkg.all;
+
+architecture a of ent is
+  use work.pkg2.all;
+begin
+  main : process
+  begin
+    wait;
+  end process;
+end architecture;
+
+entity ent2 is
+end entity;
+
+architecture a of ent2 is
+begin
+  main : process
+    use work.pkg.all;
+  begin
+    wait;
+  end process;
+end architecture;",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let decl_pos = |resolved: DiagnosticResult<ResolvedName>| match resolved {
+        Ok(ResolvedName::Overloaded(_, overloaded)) => overloaded
+            .entities()
+            .next()
+            .and_then(|ent| ent.decl_pos().cloned()),
+        Ok(resolved) => resolved.decl_pos().cloned(),
+        Err(err) => panic!("Expected resolved name, got {err:?}"),
+    };
+
+    // Visible through the context clause of the architecture
+    let process = root
+        .region_at(code.source(), code.s1("wait").start())
+        .unwrap();
+    assert_eq!(
+        decl_pos(root.resolve_in_region(process, "func")),
+        Some(code.s1("func return").s1("func").pos())
+    );
+    // Visible through the use clause in the declarative part of the architecture
+    assert_eq!(
+        decl_pos(root.resolve_in_region(process, "c")),
+        Some(code.s1("c :").s1("c").pos())
+    );
+
+    // Visible through the use clause in the declarative part of the region itself
+    let process = root
+        .region_at(code.source(), code.s("wait", 2).start())
+        .unwrap();
+    assert_eq!(
+        decl_pos(root.resolve_in_region(process, "func")),
+        Some(code.s1("func return").s1("func").pos())
+    );
+    assert!(root
+        .resolve_in_region(process.parent.unwrap(), "func")
+        .is_err());
+}
+
+#[test]
+fn resolve_name_in_region_of_package_body() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  function func return natural;
+end package;
+
+package pkg2 is
+  function func2 return natural;
+end package;
+
+use work.pkg.all;
+
+package body pkg2 is
+  constant c : natural := 0;
+
+  function func2 return natural is
+  begin
+    return c;
+  end function;
+end package body;",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let region = root
+        .region_at(code.source(), code.s1("return c").start())
+        .unwrap();
+    assert_eq!(
+        root.resolve_in_region(region, "c")
+            .unwrap()
+            .decl_pos()
+            .cloned(),
+        Some(code.s1("c :").s1("c").pos())
+    );
+    assert_eq!(
+        root.resolve_all_in_region(region, "func")
+            .unwrap()
+            .into_iter()
+            .map(|ent| ent.decl_pos().cloned())
+            .collect::<Vec<_>>(),
+        vec![Some(code.s1("func return").s1("func").pos())]
+    );
+    assert!(!root
+        .resolve_all_in_region(region, "func2")
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn character_literal_resolves_to_enumeration_literal_of_expected_type() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_std_logic_1164();
+    let code = builder.code(
+        "libname",
+        "
+library ieee;
+use ieee.std_logic_1164.all;
+
+package pkg is
+    constant c0 : std_ulogic := '0';
+    constant c1 : character := '0';
+    constant c2 : boolean := std_ulogic'('0') = c0;
+    constant c3 : boolean := character'('0') = c1;
+end package;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let literal_type = |ent: EntRef| match ent.kind() {
+        AnyEntKind::Overloaded(Overloaded::EnumLiteral(signature)) => {
+            signature.return_type().unwrap().designator().to_string()
+        }
+        _ => panic!("Expected an enumeration literal"),
+    };
+
+    let std_ulogic_zero = root
+        .search_reference(code.source(), code.s1(":= '0'").s1("'0'").start())
+        .unwrap();
+    assert_eq!(literal_type(std_ulogic_zero), "STD_ULOGIC");
+    let character_zero = root
+        .search_reference(code.source(), code.s(":= '0'", 2).s1("'0'").start())
+        .unwrap();
+    assert_eq!(literal_type(character_zero), "CHARACTER");
+
+    assert_eq!(
+        root.search_reference(code.source(), code.s1("std_ulogic'('0')").s1("'0'").start()),
+        Some(std_ulogic_zero)
+    );
+    assert_eq!(
+        root.search_reference(code.source(), code.s1("character'('0')").s1("'0'").start()),
+        Some(character_zero)
+    );
+}
+
+#[test]
+fn concatenation_resolves_to_predefined_overload() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_std_logic_1164();
+    let code = builder.code(
+        "libname",
+        "
+library ieee;
+use ieee.std_logic_1164.all;
+
+package pkg is
+    constant vec : std_logic_vector(1 downto 0) := \"01\";
+    constant bit0 : std_ulogic := '1';
+    constant c0 : std_logic_vector(2 downto 0) := vec & bit0;
+    constant c1 : std_logic_vector(2 downto 0) := bit0 & vec;
+    constant c2 : std_logic_vector(3 downto 0) := vec & vec;
+    constant c3 : std_logic_vector(1 downto 0) := bit0 & bit0;
+end package;
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let concatenation = |substr: &str| {
+        root.search_reference(code.source(), code.s1(substr).s1("&").start())
+            .unwrap()
+            .describe()
+    };
+
+    assert_eq!(
+        concatenation("vec & bit0"),
+        "operator \"&\"[STD_ULOGIC_VECTOR, STD_ULOGIC return STD_ULOGIC_VECTOR]"
+    );
+    assert_eq!(
+        concatenation("bit0 & vec"),
+        "operator \"&\"[STD_ULOGIC, STD_ULOGIC_VECTOR return STD_ULOGIC_VECTOR]"
+    );
+    assert_eq!(
+        concatenation("vec & vec"),
+        "operator \"&\"[STD_ULOGIC_VECTOR, STD_ULOGIC_VECTOR return STD_ULOGIC_VECTOR]"
+    );
+    assert_eq!(
+        concatenation("bit0 & bit0"),
+        "operator \"&\"[STD_ULOGIC, STD_ULOGIC return STD_ULOGIC_VECTOR]"
+    );
+}
@@ -373,6 +373,28 @@ end package body;
     );
 }
 
+#[test]
+fn error_on_port_type_mark_with_signal() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+  port (
+    clk : in bit;
+    data : in clk
+  );
+end entity;
+
+",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![kind_error(&code, "clk", 2, 1, "type", "signal 'clk'")],
+    );
+}
+
 #[test]
 fn error_on_type_mark_with_alias_of_non_type() {
     let mut builder = LibraryBuilder::new();
@@ -115,3 +115,59 @@ end architecture;
     let (_, diagnostics) = builder.get_analyzed_root();
     check_no_diagnostics(&diagnostics);
 }
+
+#[test]
+fn wait_condition_signal_not_in_sensitivity_clause() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal a, b, c : bit;
+begin
+  main: process
+  begin
+    wait on a, b until c = '1';
+  end process main;
+end architecture;
+
+        ",
+    );
+    let (_, diagnostics) = builder.get_analyzed_root();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::warning(
+            code.s1("until c").s1("c"),
+            "Signal 'c' is not in the sensitivity clause of the wait statement",
+        )],
+    )
+}
+
+#[test]
+fn wait_condition_signals_in_sensitivity_clause() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal a, b : bit;
+begin
+  main: process
+    variable v : bit;
+  begin
+    wait on a, b until a = b and v = '1';
+    wait until a = '1';
+  end process main;
+end architecture;
+
+        ",
+    );
+    let (_, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+}
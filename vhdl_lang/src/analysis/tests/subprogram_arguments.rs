@@ -74,6 +74,56 @@ end architecture;
     );
 }
 
+#[test]
+fn procedure_calls_in_expressions() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "lib",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+    function subpgm(arg: natural) return natural
+    is begin
+    end;
+
+    procedure theproc(arg: natural)
+    is begin
+    end;
+
+    signal thesig : natural;
+begin
+    thesig <= subpgm(0);
+    thesig <= theproc(0);
+
+    process
+        variable var : natural;
+    begin
+        var := subpgm(0);
+        var := theproc(0);
+        theproc(0);
+    end process;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s("theproc", 2),
+                "Procedure calls are not valid in names and expressions",
+            ),
+            Diagnostic::error(
+                code.s("theproc", 3),
+                "Procedure calls are not valid in names and expressions",
+            ),
+        ],
+    );
+}
+
 #[test]
 fn resolve_overloaded_subprogram_by_return_type() {
     let mut builder = LibraryBuilder::new();
@@ -413,3 +463,104 @@ end procedure;
         .related(code.s1("arg(0)"), "Previously associated here")],
     );
 }
+
+#[test]
+fn named_arguments_select_overload() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+function fun(x : integer; y : integer) return integer;
+function fun(x : integer; z : integer) return character;
+
+constant c0 : integer := fun(x => 1, y => 2);
+constant c1 : character := fun(x => 1, z => 2);
+constant c2 : integer := fun(x => 1, w => 2);
+",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("fun(x => 1, w => 2)").s1("fun"),
+            "Could not resolve call to 'fun'",
+        )
+        .related(
+            code.s1("fun(x : integer; y").s1("fun"),
+            "Does not match function fun[INTEGER, INTEGER return INTEGER]",
+        )
+        .related(
+            code.s1("fun(x : integer; z").s1("fun"),
+            "Does not match function fun[INTEGER, INTEGER return CHARACTER]",
+        )],
+    );
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("fun(x => 1, y").start()),
+        Some(code.s1("fun(x : integer; y").s1("fun").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("fun(x => 1, z").start()),
+        Some(code.s1("fun(x : integer; z").s1("fun").pos())
+    );
+}
+
+#[test]
+fn concurrent_procedure_call_with_signal_actual() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+    procedure theproc(signal arg : inout natural) is
+    begin
+    end;
+
+    signal thesig : natural;
+begin
+    theproc(thesig);
+end architecture;
+",
+    );
+
+    check_no_diagnostics(&builder.analyze());
+}
+
+#[test]
+fn concurrent_procedure_call_with_variable_actual() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+    procedure theproc(variable arg : inout natural; variable arg2 : out natural) is
+    begin
+    end;
+
+    shared variable thevar : natural;
+begin
+    theproc(thevar, arg2 => thevar);
+end architecture;
+",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s("thevar", 2),
+                "shared variable 'thevar' cannot be associated with variable 'arg' : inout in a concurrent procedure call",
+            ),
+            Diagnostic::error(
+                code.s("thevar", 3),
+                "shared variable 'thevar' cannot be associated with variable 'arg2' : out in a concurrent procedure call",
+            ),
+        ],
+    );
+}
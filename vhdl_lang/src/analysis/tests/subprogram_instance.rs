@@ -460,7 +460,6 @@ end bar;
 // LRM 4.2.1: "an uninstantiated subprogram shall not be used as a resolution
 // function or used as a conversion function in an association list."
 #[test]
-#[ignore]
 pub fn generic_subprogram_cannot_be_used_as_resolution_function() {
     let mut builder = LibraryBuilder::new();
     let code = builder.in_declarative_region(
@@ -475,7 +474,7 @@ subtype x is resolved bit;
         builder.analyze(),
         vec![Diagnostic::error(
             code.s1("subtype x is resolved bit").s1("resolved"),
-            "uninstantiated function resolved[F] return F cannot be used as resolution function",
+            "uninstantiated function resolved[F return F] cannot be used as resolution function",
         )],
     );
 }
@@ -551,3 +550,33 @@ procedure proc2 is new foo [bit, bit] generic map (T => bit);
     let diagnostics = builder.analyze();
     check_no_diagnostics(&diagnostics);
 }
+
+#[test]
+pub fn generic_map_with_type_and_subprogram_actuals() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "\
+function apply
+    generic (type T; function f(x : T) return T)
+    parameter (x : T) return T;
+
+function inc(x : integer) return integer;
+function inc_real(x : real) return real;
+
+function apply_int is new apply generic map (T => integer, f => inc);
+function apply_bad is new apply generic map (T => integer, f => inc_real);
+    ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::error(
+            code.s1("f => inc_real").s1("inc_real"),
+            "Cannot map 'inc_real' to subprogram generic f[INTEGER return INTEGER]",
+        )
+        .related(
+            code.s1("inc_real"),
+            "Does not match function inc_real[REAL return REAL]",
+        )],
+    );
+}
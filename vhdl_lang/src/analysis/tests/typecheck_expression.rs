@@ -594,6 +594,29 @@ signal sig2 : sig0'subtype := (others => 0); -- ok
     );
 }
 
+#[test]
+fn array_attributes_of_subtype_mark() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+subtype my_vec_subtype is integer_vector(0 to 7);
+constant len : natural := my_vec_subtype'length;
+constant low : natural := my_vec_subtype'low;
+type arr_t is array (my_vec_subtype'range) of bit;
+constant bad : boolean := my_vec_subtype'length;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s("my_vec_subtype'length", 2),
+            "Expression of type universal_integer does not match type 'BOOLEAN'",
+        )],
+    );
+}
+
 #[test]
 fn test_typechecks_expression_for_type_mark_with_element_attribute() {
     let mut builder = LibraryBuilder::new();
@@ -862,6 +885,280 @@ constant bad2 : arr2_t := (others => a1);
     );
 }
 
+#[test]
+fn array_aggregate_with_mixed_choices() {
+    let mut builder = LibraryBuilder::new();
+    builder.in_declarative_region(
+        "
+type arr_t is array (0 to 7) of natural;
+constant good1 : arr_t := (0 => 1, 1 to 2 => 2, 5 downto 4 | 6 => 3, others => 0);
+constant good2 : arr_t := (1, 2, 3, others => 0);
+constant good3 : arr_t := (2 + 1 => 1, 0 to 1 => 0, 4 to 3 => 5, others => 2);
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn array_aggregate_with_overlapping_choices() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type arr_t is array (0 to 7) of natural;
+constant bad1 : arr_t := (3 => 1, 2 to 4 => 2, others => 0);
+constant bad2 : arr_t := (0 to 5 => 1, 7 downto 5 => 2);
+constant bad3 : arr_t := (6 => 1, 6 => 2, others => 0);
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(code.s1("2 to 4"), "Index 3 has already been associated")
+                .related(code.s1("(3 => 1").s1("3"), "Previously associated here"),
+            Diagnostic::error(code.s1("7 downto 5"), "Index 5 has already been associated")
+                .related(code.s1("0 to 5"), "Previously associated here"),
+            Diagnostic::error(
+                code.s("6 =>", 2).s1("6"),
+                "Index 6 has already been associated",
+            )
+            .related(code.s1("6 =>").s1("6"), "Previously associated here"),
+        ],
+    );
+}
+
+#[test]
+fn array_aggregate_others_in_constrained_context() {
+    let mut builder = LibraryBuilder::new();
+    builder.in_declarative_region(
+        "
+subtype word_t is bit_vector(7 downto 0);
+constant c0 : bit_vector(7 downto 0) := (others => '0');
+constant c1 : word_t := (others => '0');
+constant c2 : bit_vector := word_t'(others => '0');
+signal s0 : bit_vector(3 downto 0) := (0 => '1', others => '0');
+        ",
+    );
+
+    check_no_diagnostics(&builder.analyze());
+}
+
+#[test]
+fn array_aggregate_others_requires_known_index_range() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+constant bad1 : bit_vector := (others => '0');
+constant bad2 : bit_vector(0 to 3) := bit_vector'(0 => '1', others => '0');
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("others"),
+                "'others' is not allowed since the index range of unconstrained array type 'BIT_VECTOR' is not known",
+            ),
+            Diagnostic::error(
+                code.s("others", 2),
+                "'others' is not allowed since the index range of unconstrained array type 'BIT_VECTOR' is not known",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn array_aggregate_others_requires_known_index_range_of_subtype() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_std_logic_1164();
+    let code = builder.code(
+        "libname",
+        "
+library ieee;
+use ieee.std_logic_1164.all;
+
+package pkg is
+    subtype u_t is bit_vector;
+    constant bad1 : std_logic_vector := (others => '0');
+    constant bad2 : u_t := (others => '0');
+    constant good : std_logic_vector(7 downto 0) := (others => '0');
+end package;
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("others"),
+                "'others' is not allowed since the index range of unconstrained subtype 'STD_LOGIC_VECTOR' is not known",
+            ),
+            Diagnostic::error(
+                code.s("others", 2),
+                "'others' is not allowed since the index range of unconstrained subtype 'u_t' is not known",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn array_aggregate_others_must_be_last_and_alone() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type arr_t is array (0 to 7) of natural;
+constant bad1 : arr_t := (others => 0, 1 => 1);
+constant bad2 : arr_t := (1 | others => 0);
+constant bad3 : arr_t := (1, 2 => 2, others => 0);
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("others => 0, 1").s1("others"),
+                "'others' must be the only choice of the last association",
+            ),
+            Diagnostic::error(
+                code.s1("1 | others").s1("others"),
+                "'others' must be the only choice of the last association",
+            ),
+            Diagnostic::error(
+                code.s1("2 => 2").s1("2"),
+                "Named associations cannot follow positional associations in an array aggregate",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn array_aggregate_choice_outside_of_index_range() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type arr_t is array (0 to 7) of natural;
+type rev_t is array (7 downto 0) of natural;
+constant bad1 : arr_t := (8 => 1, others => 0);
+constant bad2 : rev_t := (6 to 9 => 1, others => 0);
+constant good : rev_t := (7 downto 4 => 1, 3 downto 0 => 0);
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("8 => 1").s1("8"),
+                "Index 8 is outside of the index range 0 to 7",
+            ),
+            Diagnostic::error(
+                code.s1("6 to 9"),
+                "Index 9 is outside of the index range 7 downto 0",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn array_aggregate_without_others_must_associate_every_index() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type arr_t is array (0 to 3) of natural;
+constant good1 : arr_t := (0 => 1, 1 to 3 => 0);
+constant good2 : arr_t := (1, 2, 3, 4);
+constant good3 : arr_t := (0 => 1, others => 0);
+constant bad1 : arr_t := (0 => 1, 1 => 2, 3 => 0);
+constant bad2 : arr_t := (1, 2, 3);
+constant bad3 : arr_t := arr_t'(3 downto 1 => 0);
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("(0 => 1, 1 => 2, 3 => 0)"),
+                "Index 2 of array type 'arr_t' is not associated, consider an 'others' choice",
+            ),
+            Diagnostic::error(
+                code.s1("(1, 2, 3)"),
+                "Index 3 of array type 'arr_t' is not associated, consider an 'others' choice",
+            ),
+            Diagnostic::error(
+                code.s1("(3 downto 1 => 0)"),
+                "Index 0 of array type 'arr_t' is not associated, consider an 'others' choice",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn array_aggregate_choices_of_constrained_subtype() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_std_logic_1164();
+    let code = builder.code(
+        "libname",
+        "
+library ieee;
+use ieee.std_logic_1164.all;
+
+package pkg is
+    subtype byte_t is bit_vector(0 to 7);
+    constant bad1 : byte_t := (8 => '1', others => '0');
+    constant bad2 : byte_t := ('1', '0');
+    constant bad3 : std_logic_vector(7 downto 0) := (8 => '1', others => '0');
+    constant bad4 : std_logic_vector(7 downto 0) := (7 downto 1 => '0');
+    constant good : byte_t := (0 to 3 => '1', 4 to 7 => '0');
+end package;
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("8 => '1'").s1("8"),
+                "Index 8 is outside of the index range 0 to 7",
+            ),
+            Diagnostic::error(
+                code.s1("('1', '0')"),
+                "Index 2 of subtype 'byte_t' is not associated, consider an 'others' choice",
+            ),
+            Diagnostic::error(
+                code.s("8 => '1'", 2).s1("8"),
+                "Index 8 is outside of the index range 7 downto 0",
+            ),
+            Diagnostic::error(
+                code.s1("(7 downto 1 => '0')"),
+                "Index 0 of subtype 'STD_LOGIC_VECTOR' is not associated, consider an 'others' choice",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn array_aggregate_with_positional_slices_associates_every_index() {
+    let mut builder = LibraryBuilder::new();
+    builder.in_declarative_region(
+        "
+type arr_t is array (0 to 3) of bit;
+constant a : arr_t := \"0101\";
+constant b : arr_t := (a(0 to 1), a(2 to 3));
+        ",
+    );
+
+    check_no_diagnostics(&builder.analyze());
+}
+
 #[test]
 fn record_aggregate_must_be_simple_name() {
     let mut builder = LibraryBuilder::new();
@@ -1013,7 +1310,7 @@ constant good1 : integer_vector := (0, 1, 2);
 constant good2 : arr2_t := ((0, 1), (2, 3));
 constant good3 : rec_t := (field => 0);
 constant bad1 : integer_vector := (3, 4, 'c');
-constant bad2 : integer_vector := (others => 'd');
+constant bad2 : integer_vector(0 to 1) := (others => 'd');
 constant bad3 : integer_vector := (1 to 3 => 'e');
 constant bad4 : rec_t := (field => 'f');
 
@@ -1397,6 +1694,38 @@ end;
     );
 }
 
+#[test]
+fn typecheck_nested_function_return_statement() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+function fun(arg : boolean) return natural is
+  variable good : natural;
+  variable bad : real;
+begin
+  if arg then
+    return good;
+  end if;
+
+  for i in 0 to 1 loop
+    return bad;
+  end loop;
+
+  return good + 1;
+end;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("return bad").s1("bad"),
+            "variable 'bad' of real type 'REAL' does not match subtype 'NATURAL'",
+        )],
+    );
+}
+
 #[test]
 fn typecheck_report_statement() {
     let mut builder = LibraryBuilder::new();
@@ -1468,6 +1797,166 @@ end;
     );
 }
 
+#[test]
+fn typecheck_assert_statement_with_objects() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+procedure wrapper is
+  variable cond : boolean;
+  variable num : natural;
+  variable msg : string(1 to 3);
+  variable sev : severity_level;
+begin
+   assert cond report msg severity sev;
+   assert num report msg severity sev;
+   assert cond report num severity sev;
+   assert cond report msg severity num;
+   report num;
+end;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("assert num").s1("num"),
+                "subtype 'NATURAL' cannot be implictly converted to type 'BOOLEAN'. Operator ?? is not defined for this type.",
+            ),
+            Diagnostic::error(
+                code.s1("report num severity").s1("num"),
+                "variable 'num' of subtype 'NATURAL' does not match array type 'STRING'",
+            ),
+            Diagnostic::error(
+                code.s1("severity num").s1("num"),
+                "variable 'num' of subtype 'NATURAL' does not match type 'SEVERITY_LEVEL'",
+            ),
+            Diagnostic::error(
+                code.s1("report num;").s1("num"),
+                "variable 'num' of subtype 'NATURAL' does not match array type 'STRING'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn typecheck_exponentiation() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+constant x : real := 2.0;
+constant n : natural := 3;
+constant good1 : integer := 2 ** 8;
+constant good2 : real := x ** 3;
+constant good3 : real := x ** n;
+constant bad1 : integer := 2 ** 1.5;
+constant bad2 : real := x ** x;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("2 ** 1.5").s1("**"),
+                "Found no match for operator \"**\"",
+            ),
+            Diagnostic::error(
+                code.s1("x ** x").s1("**"),
+                "Found no match for operator \"**\"",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn signal_attribute_is_not_static() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal clk, q : bit;
+  signal sel : boolean;
+begin
+  process
+  begin
+    if clk'event and clk = '1' then
+      q <= '1';
+    end if;
+
+    case sel is
+      when clk'event => q <= '0';
+      when others => null;
+    end case;
+    wait;
+  end process;
+
+  with sel select q <= '1' when not clk'stable, '0' when others;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("when clk'event").s1("event"),
+                "Signal attribute 'event' is not static and cannot be used as a choice",
+            ),
+            Diagnostic::error(
+                code.s1("stable"),
+                "Signal attribute 'stable' is not static and cannot be used as a choice",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn typecheck_concurrent_assert_statement() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal cond : boolean;
+  signal num : natural;
+  constant msg : string := \"msg\";
+begin
+  assert cond report msg severity warning;
+  postponed assert cond report msg;
+  assert num report msg;
+  assert cond report msg severity num;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("assert num").s1("num"),
+                "subtype 'NATURAL' cannot be implictly converted to type 'BOOLEAN'. Operator ?? is not defined for this type.",
+            ),
+            Diagnostic::error(
+                code.s1("severity num").s1("num"),
+                "signal 'num' of subtype 'NATURAL' does not match type 'SEVERITY_LEVEL'",
+            ),
+        ],
+    );
+}
+
 #[test]
 fn resolves_unambiguous_boolean_reference() {
     let mut builder = LibraryBuilder::new();
@@ -1629,6 +2118,32 @@ subtype bad2_t is string range 2 to 3;
     );
 }
 
+#[test]
+fn typecheck_scalar_constraint_in_object_declaration() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+signal good : integer range -1 to 1;
+signal bad : real range 0 to 1.0;
+constant good_c : integer range -1 to 1 := 0;
+
+procedure proc is
+begin
+  good <= good_c;
+end procedure;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("range 0 to").s1("0"),
+            "integer literal does not match real type 'REAL'",
+        )],
+    );
+}
+
 #[test]
 fn typecheck_array_index_constraint() {
     let mut builder = LibraryBuilder::new();
@@ -1865,3 +2380,140 @@ end package;
     let diagnostics = builder.analyze();
     check_no_diagnostics(&diagnostics);
 }
+
+#[test]
+fn array_type_conversion_checks_element_types() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_std_logic_1164();
+    let code = builder.code(
+        "libname",
+        "
+library ieee;
+use ieee.std_logic_1164.all;
+
+package pkg is
+    type unsigned is array (natural range <>) of std_ulogic;
+
+    constant unsigned_val : unsigned(1 downto 0) := \"01\";
+    constant int_val : integer_vector(1 downto 0) := (0, 1);
+
+    constant good : std_logic_vector(1 downto 0) := std_logic_vector(unsigned_val);
+    constant bad : std_logic_vector(1 downto 0) := std_logic_vector(int_val);
+end package;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("std_logic_vector(int_val)").s1("int_val"),
+            "array type 'INTEGER_VECTOR' cannot be converted to subtype 'STD_LOGIC_VECTOR'",
+        )],
+    );
+}
+
+#[test]
+fn matching_case_choice_coverage() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_std_logic_1164();
+    let code = builder.code(
+        "libname",
+        "
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+    signal sel : std_ulogic_vector(2 downto 0);
+    signal bit_sel : std_ulogic;
+begin
+    process
+    begin
+        case? sel is
+            when \"1--\" => null;
+            when \"01-\" => null;
+            when \"00-\" => null;
+        end case?;
+
+        case? sel is
+            when \"1--\" => null;
+            when \"00-\" => null;
+        end case?;
+
+        case? sel is
+            when \"1--\" => null;
+            when others => null;
+        end case?;
+
+        case? bit_sel is
+            when '1' => null;
+        end case?;
+        wait;
+    end process;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s("sel", 4),
+                "Choices of matching case statement do not cover all values, missing \"010\"",
+            ),
+            Diagnostic::error(
+                code.s1("case? bit_sel").s1("bit_sel"),
+                "Choices of matching case statement do not cover all values, missing '0'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn driving_attributes_require_a_driven_signal() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+  port (
+    din : in bit_vector(3 downto 0);
+    dout : out bit_vector(3 downto 0);
+    active : out boolean
+  );
+end entity;
+
+architecture a of ent is
+begin
+  process
+    variable v : bit_vector(3 downto 0);
+  begin
+    v := dout'driving_value;
+    active <= dout'driving;
+    v := din'driving_value;
+    active <= din'driving;
+    wait;
+  end process;
+end architecture;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("din'driving_value").s1("din"),
+                "'driving_value attribute cannot be used with interface signal 'din' of mode in",
+            ),
+            Diagnostic::error(
+                code.s1("din'driving;").s1("din"),
+                "'driving attribute cannot be used with interface signal 'din' of mode in",
+            ),
+        ],
+    );
+}
@@ -15,6 +15,7 @@ use std::sync::Arc;
 pub struct LibraryBuilder {
     code_builder: CodeBuilder,
     libraries: HashMap<Symbol, Vec<Code>>,
+    predefined_packages: Vec<(Symbol, Symbol)>,
 }
 
 impl LibraryBuilder {
@@ -22,6 +23,7 @@ impl LibraryBuilder {
         LibraryBuilder {
             code_builder: CodeBuilder::new(),
             libraries: HashMap::default(),
+            predefined_packages: Vec::new(),
         }
     }
 
@@ -69,12 +71,23 @@ end architecture;"
         self.add_code("ieee", std_logic_1164);
     }
 
+    pub fn add_predefined_package(&mut self, library_name: &str, package_name: &str) {
+        self.predefined_packages.push((
+            self.code_builder.symbol(library_name),
+            self.code_builder.symbol(package_name),
+        ));
+    }
+
     pub fn get_analyzed_root(&self) -> (DesignRoot, Vec<Diagnostic>) {
         let mut root = DesignRoot::new(self.code_builder.symbols.clone());
         let mut diagnostics = Vec::new();
 
         add_standard_library(self.symbols(), &mut root);
 
+        for (library_name, package_name) in self.predefined_packages.iter() {
+            root.add_predefined_package(library_name.clone(), package_name.clone());
+        }
+
         for (library_name, codes) in self.libraries.iter() {
             for code in codes {
                 root.add_design_file(library_name.clone(), code.design_file());
@@ -9,6 +9,8 @@ use crate::ast::*;
 use crate::data::*;
 use crate::named_entity::{Signature, *};
 use analyze::*;
+use names::ResolvedName;
+use range::{static_discrete_range, static_range};
 
 impl<'a> AnalyzeContext<'a> {
     pub fn resolve_subtype_indication(
@@ -17,15 +19,44 @@ impl<'a> AnalyzeContext<'a> {
         subtype_indication: &mut SubtypeIndication,
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> EvalResult<Subtype<'a>> {
-        // @TODO more
+        let subtype = self.resolve_subtype_indication_allow_incomplete(
+            scope,
+            subtype_indication,
+            diagnostics,
+        )?;
+
+        // LRM 5.4.2: Prior to the end of the corresponding full type declaration, the only
+        // allowed use of an incomplete type is as the designated type of an access type
+        if matches!(subtype.type_mark().kind(), Type::Incomplete) {
+            diagnostics.error(
+                &subtype_indication.type_mark.pos,
+                format!(
+                    "Incomplete type '{}' cannot be used before its full type declaration",
+                    subtype.type_mark().designator()
+                ),
+            );
+            return Err(EvalError::Unknown);
+        }
+
+        Ok(subtype)
+    }
+
+    fn resolve_subtype_indication_allow_incomplete(
+        &self,
+        scope: &Scope<'a>,
+        subtype_indication: &mut SubtypeIndication,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> EvalResult<Subtype<'a>> {
         let SubtypeIndication {
+            resolution,
             type_mark,
             constraint,
-            ..
         } = subtype_indication;
 
         let base_type = self.resolve_type_mark(scope, type_mark, diagnostics)?;
 
+        self.analyze_resolution_indication(scope, base_type, resolution, diagnostics)?;
+
         if let Some(constraint) = constraint {
             self.analyze_subtype_constraint(
                 scope,
@@ -36,7 +67,150 @@ impl<'a> AnalyzeContext<'a> {
             )?;
         }
 
-        Ok(Subtype::new(base_type))
+        let static_constraint = match constraint {
+            Some(constraint) => match constraint.item {
+                SubtypeConstraint::Range(ref range) => static_range(range),
+                SubtypeConstraint::Array(ref dranges, _) => match dranges.as_slice() {
+                    [drange] => static_discrete_range(drange),
+                    _ => None,
+                },
+                SubtypeConstraint::Record(_) => None,
+            },
+            // A subtype without constraint inherits the constraint of its type mark
+            None => match base_type.kind() {
+                Type::Subtype(subtype) => subtype.constraint(),
+                _ => None,
+            },
+        };
+
+        let mut subtype = Subtype::with_constraint(base_type, static_constraint);
+        subtype.has_index_constraint = matches!(
+            constraint.as_ref().map(|constraint| &constraint.item),
+            Some(SubtypeConstraint::Array(..))
+        );
+        Ok(subtype)
+    }
+
+    /// LRM 6.3 Subtype declarations
+    fn analyze_resolution_indication(
+        &self,
+        scope: &Scope<'a>,
+        typ: TypeEnt<'a>,
+        resolution: &mut ResolutionIndication,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        match resolution {
+            ResolutionIndication::FunctionName(name) => {
+                self.analyze_resolution_function(scope, typ, name, diagnostics)?;
+            }
+            ResolutionIndication::ArrayElement(name) => {
+                if let Some((elem_type, _)) = typ.array_type() {
+                    self.analyze_resolution_function(scope, elem_type, name, diagnostics)?;
+                } else {
+                    diagnostics.error(
+                        &name.pos,
+                        format!(
+                            "Element resolution is not allowed for non-array {}",
+                            typ.describe()
+                        ),
+                    );
+                }
+            }
+            ResolutionIndication::Record(element_resolutions) => {
+                let Type::Record(region) = typ.base().kind() else {
+                    if let Some(first) = element_resolutions.first() {
+                        diagnostics.error(
+                            &first.ident.pos,
+                            format!(
+                                "Record element resolution is not allowed for non-record {}",
+                                typ.describe()
+                            ),
+                        );
+                    }
+                    return Ok(());
+                };
+
+                for element_resolution in element_resolutions.iter_mut() {
+                    let designator = Designator::Identifier(element_resolution.ident.item.clone());
+                    if let Some(elem) = region.lookup(&designator) {
+                        self.analyze_resolution_indication(
+                            scope,
+                            elem.type_mark(),
+                            &mut element_resolution.resolution,
+                            diagnostics,
+                        )?;
+                    } else {
+                        diagnostics.push(Diagnostic::no_declaration_within(
+                            &typ,
+                            &element_resolution.ident.pos,
+                            &designator,
+                        ));
+                    }
+                }
+            }
+            ResolutionIndication::Unresolved => {}
+        }
+        Ok(())
+    }
+
+    /// A resolution function of a type takes a single parameter that is a
+    /// one-dimensional unconstrained array of the type and returns the type
+    fn analyze_resolution_function(
+        &self,
+        scope: &Scope<'a>,
+        typ: TypeEnt<'a>,
+        name: &mut WithPos<Name>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let Some(resolved) =
+            as_fatal(self.name_resolve(scope, &name.pos, &mut name.item, diagnostics))?
+        else {
+            return Ok(());
+        };
+
+        let ResolvedName::Overloaded(des, overloaded) = resolved else {
+            diagnostics.error(
+                &name.pos,
+                format!(
+                    "{} cannot be used as a resolution function",
+                    resolved.describe_type()
+                ),
+            );
+            return Ok(());
+        };
+
+        let candidates: Vec<_> = overloaded
+            .entities()
+            .filter(|ent| !ent.is_uninst_subprogram() && is_resolution_function_of(ent, typ.base()))
+            .collect();
+
+        if let [ent] = candidates.as_slice() {
+            if let Some(reference) = name.item.suffix_reference_mut() {
+                reference.set_unique_reference(ent);
+            }
+        } else if let Some(ent) = candidates
+            .is_empty()
+            .then(|| overloaded.entities().find(|ent| ent.is_uninst_subprogram()))
+            .flatten()
+        {
+            diagnostics.error(
+                &des.pos,
+                format!(
+                    "uninstantiated {} cannot be used as resolution function",
+                    ent.describe()
+                ),
+            );
+        } else if candidates.is_empty() {
+            diagnostics.error(
+                &des.pos,
+                format!(
+                    "'{}' is not a resolution function of {}",
+                    des.item,
+                    typ.describe()
+                ),
+            );
+        }
+        Ok(())
     }
 
     pub(crate) fn analyze_type_declaration(
@@ -51,6 +225,17 @@ impl<'a> AnalyzeContext<'a> {
     ) -> FatalResult {
         match type_decl.def {
             TypeDefinition::Enumeration(ref mut enumeration) => {
+                // LRM 5.2.2.1: An enumeration type definition has at least one literal
+                if enumeration.is_empty() {
+                    diagnostics.error(
+                        type_decl.ident.pos(),
+                        format!(
+                            "Enumeration type '{}' must have at least one literal",
+                            type_decl.ident.tree.item
+                        ),
+                    );
+                }
+
                 let enum_type = TypeEnt::define_with_opt_id(
                     self.arena,
                     overwrite_id,
@@ -215,6 +400,17 @@ impl<'a> AnalyzeContext<'a> {
                 }
             }
             TypeDefinition::Record(ref mut element_decls) => {
+                // LRM 5.3.3: A record type definition has at least one element declaration
+                if element_decls.is_empty() {
+                    diagnostics.error(
+                        type_decl.ident.pos(),
+                        format!(
+                            "Record type '{}' must have at least one element",
+                            type_decl.ident.tree.item
+                        ),
+                    );
+                }
+
                 let type_ent = TypeEnt::define_with_opt_id(
                     self.arena,
                     overwrite_id,
@@ -257,8 +453,11 @@ impl<'a> AnalyzeContext<'a> {
                 }
             }
             TypeDefinition::Access(ref mut subtype_indication) => {
-                let subtype =
-                    self.resolve_subtype_indication(scope, subtype_indication, diagnostics);
+                let subtype = self.resolve_subtype_indication_allow_incomplete(
+                    scope,
+                    subtype_indication,
+                    diagnostics,
+                );
                 if let Some(subtype) = as_fatal(subtype)? {
                     let type_ent = TypeEnt::define_with_opt_id(
                         self.arena,
@@ -299,13 +498,25 @@ impl<'a> AnalyzeContext<'a> {
                 };
 
                 let is_1d = indexes.len() == 1;
+                let is_constrained = array_indexes
+                    .iter()
+                    .all(|index| matches!(index, ArrayIndex::Discrete(..)));
+                let constraint = match array_indexes.as_slice() {
+                    [ArrayIndex::Discrete(drange)] => static_discrete_range(drange),
+                    _ => None,
+                };
                 let array_ent = TypeEnt::define_with_opt_id(
                     self.arena,
                     overwrite_id,
                     &mut type_decl.ident,
                     parent,
                     None,
-                    Type::Array { indexes, elem_type },
+                    Type::Array {
+                        indexes,
+                        elem_type,
+                        is_constrained,
+                        constraint,
+                    },
                 );
 
                 scope.add(array_ent.into(), diagnostics);
@@ -504,7 +715,10 @@ impl<'a> AnalyzeContext<'a> {
     ) -> FatalResult {
         match constraint {
             SubtypeConstraint::Array(ref mut dranges, ref mut constraint) => {
-                if let Type::Array { indexes, elem_type } = base_type.kind() {
+                if let Type::Array {
+                    indexes, elem_type, ..
+                } = base_type.kind()
+                {
                     for (idx, drange) in dranges.iter_mut().enumerate() {
                         if let Some(index_typ) = indexes.get(idx) {
                             if let Some(index_typ) = index_typ {
@@ -613,3 +827,23 @@ impl<'a> AnalyzeContext<'a> {
             .map(|_| ())
     }
 }
+
+fn is_resolution_function_of(ent: &OverloadedEnt<'_>, base: BaseType<'_>) -> bool {
+    if ent.return_type().map(|typ| typ.base()) != Some(base) || ent.formals().len() != 1 {
+        return false;
+    }
+
+    let Some(formal) = ent.formals().nth(0) else {
+        return false;
+    };
+
+    matches!(
+        formal.base_type().kind(),
+        Type::Array {
+            indexes,
+            elem_type,
+            is_constrained: false,
+            ..
+        } if indexes.len() == 1 && elem_type.base() == base
+    )
+}
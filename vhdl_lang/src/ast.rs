@@ -19,7 +19,7 @@ pub(crate) use any_design_unit::*;
 
 use crate::data::*;
 use crate::named_entity::{EntityId, Reference};
-use crate::syntax::{Token, TokenAccess, TokenId};
+use crate::syntax::{Comment, Token, TokenAccess, TokenId};
 
 /// LRM 15.8 Bit string literals
 #[derive(PartialEq, Eq, Copy, Clone, Debug)]
@@ -252,7 +252,7 @@ pub struct PhysicalLiteral {
 pub enum Literal {
     String(Latin1String),
     BitString(BitString),
-    Character(u8),
+    Character(WithRef<u8>),
     AbstractLiteral(AbstractLiteral),
     Physical(PhysicalLiteral),
     Null,
@@ -813,6 +813,7 @@ pub enum Declaration {
     Use(UseClause),
     Package(PackageInstantiation),
     Configuration(ConfigurationSpecification),
+    Disconnection(DisconnectionSpecification),
 }
 
 /// LRM 10.2 Wait statement
@@ -823,6 +824,15 @@ pub struct WaitStatement {
     pub timeout_clause: Option<WithPos<Expression>>,
 }
 
+/// The values of the predefined type SEVERITY_LEVEL
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum SeverityLevel {
+    Note,
+    Warning,
+    Error,
+    Failure,
+}
+
 /// LRM 10.3 Assertion statement
 #[derive(PartialEq, Debug, Clone)]
 pub struct AssertStatement {
@@ -1205,10 +1215,106 @@ impl<T> Default for SeparatedList<T> {
     }
 }
 
+impl<T> SeparatedList<T> {
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.items.iter_mut()
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.items.as_mut_slice()
+    }
+
+    pub fn first(&self) -> Option<&T> {
+        self.items.first()
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    /// The separator tokens where the separator at index `i` follows the item at index `i`
+    pub fn separators(&self) -> &[TokenId] {
+        &self.tokens
+    }
+
+    /// The comments between the element at `index` and the separator that precedes it.
+    /// A comment on the same line as the separator is a trailing comment of the separator
+    /// but is considered to belong to the following element.
+    /// Comments before the first element belong to the construct that contains the list.
+    pub fn leading_comments<'a>(&self, index: usize, ctx: &'a dyn TokenAccess) -> Vec<&'a Comment> {
+        let Some(separator) = index.checked_sub(1).and_then(|idx| self.tokens.get(idx)) else {
+            return Vec::new();
+        };
+        if index >= self.len() {
+            return Vec::new();
+        }
+
+        let separator_kind = ctx.get_token(*separator).kind;
+        let mut comments = Vec::new();
+        let mut id = *separator;
+        loop {
+            let token = ctx.get_token(id);
+            if let Some(ref token_comments) = token.comments {
+                if id != *separator {
+                    comments.extend(token_comments.leading.iter());
+                }
+                if token.kind == separator_kind {
+                    comments.extend(token_comments.trailing.iter());
+                }
+            }
+            // Extraneous separators are skipped by the parser
+            if id != *separator && token.kind != separator_kind {
+                break;
+            }
+            id = id.next();
+        }
+        comments
+    }
+}
+
+impl<T> IntoIterator for SeparatedList<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SeparatedList<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut SeparatedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter_mut()
+    }
+}
+
 impl SeparatedList<AssociationElement> {
     /// Returns an iterator over the formal elements of this list
     pub fn formals(&self) -> impl Iterator<Item = Option<EntityId>> + '_ {
-        self.items.iter().filter_map(|el| match &el.formal {
+        self.iter().filter_map(|el| match &el.formal {
             None => None,
             Some(name) => match &name.item {
                 Name::Designator(desi) => Some(desi.reference.get()),
@@ -1308,6 +1414,23 @@ pub struct ConfigurationSpecification {
     pub vunit_bind_inds: Vec<VUnitBindingIndication>,
 }
 
+/// LRM 7.4 Disconnection specification
+#[derive(PartialEq, Debug, Clone)]
+pub enum GuardedSignalList {
+    Names(NameList),
+    Others,
+    All,
+}
+
+/// LRM 7.4 Disconnection specification
+#[with_token_span]
+#[derive(PartialEq, Debug, Clone)]
+pub struct DisconnectionSpecification {
+    pub signal_list: GuardedSignalList,
+    pub type_mark: WithPos<TypeMark>,
+    pub after: WithPos<Expression>,
+}
+
 /// LRM 3.4 Configuration declarations
 #[derive(PartialEq, Debug, Clone)]
 pub enum ConfigurationDeclarativeItem {
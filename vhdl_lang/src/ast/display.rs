@@ -334,7 +334,7 @@ impl Display for Literal {
         match self {
             Literal::String(ref val) => write!(f, "\"{val}\""),
             Literal::BitString(ref val) => write!(f, "{val}"),
-            Literal::Character(byte) => write!(f, "'{}'", *byte as char),
+            Literal::Character(byte) => write!(f, "'{}'", byte.item as char),
             Literal::AbstractLiteral(ref val) => write!(f, "{val}"),
             Literal::Physical(ref val) => write!(f, "{val}"),
             Literal::Null => write!(f, "null"),
@@ -910,7 +910,7 @@ fn write_separated_list<T: Display>(
     separator: &str,
 ) -> Result {
     let mut first = true;
-    for assoc in &list.items {
+    for assoc in list {
         if !first {
             writeln!(f, "{separator}")?;
         }
@@ -973,7 +973,7 @@ impl Display for InterfacePackageDeclaration {
         match &self.generic_map {
             InterfacePackageGenericMapAspect::Map(assoc_list) => {
                 let mut first = true;
-                for assoc in &assoc_list.items {
+                for assoc in assoc_list {
                     if first {
                         write!(f, "\n    {assoc}")?;
                     } else {
@@ -1077,7 +1077,7 @@ impl Display for PackageInstantiation {
         write!(f, "package {} is new {}", self.ident, self.package_name)?;
         if let Some(assoc_list) = &self.generic_map {
             let mut first = true;
-            for assoc in &assoc_list.list.items {
+            for assoc in &assoc_list.list {
                 if first {
                     write!(f, "\n  generic map (\n    {assoc}")?;
                 } else {
@@ -663,18 +663,35 @@ impl Search for WithPos<SubtypeConstraint> {
 
 impl Search for SubtypeIndication {
     fn search(&self, ctx: &dyn TokenAccess, searcher: &mut impl Searcher) -> SearchResult {
-        // @TODO more
         let SubtypeIndication {
+            resolution,
             type_mark,
             constraint,
-            ..
         } = self;
+        return_if_found!(resolution.search(ctx, searcher));
         return_if_found!(type_mark.search(ctx, searcher));
         return_if_found!(constraint.search(ctx, searcher));
         NotFound
     }
 }
 
+impl Search for ResolutionIndication {
+    fn search(&self, ctx: &dyn TokenAccess, searcher: &mut impl Searcher) -> SearchResult {
+        match self {
+            ResolutionIndication::FunctionName(name) | ResolutionIndication::ArrayElement(name) => {
+                name.search(ctx, searcher)
+            }
+            ResolutionIndication::Record(element_resolutions) => {
+                for element_resolution in element_resolutions.iter() {
+                    return_if_found!(element_resolution.resolution.search(ctx, searcher));
+                }
+                NotFound
+            }
+            ResolutionIndication::Unresolved => NotFound,
+        }
+    }
+}
+
 impl Search for WithPos<TypeMark> {
     fn search(&self, ctx: &dyn TokenAccess, searcher: &mut impl Searcher) -> SearchResult {
         return_if_finished!(searcher.search_with_pos(ctx, &self.pos));
@@ -854,6 +871,9 @@ fn search_pos_expr(
             Literal::Physical(PhysicalLiteral { unit, .. }) => {
                 searcher.search_ident_ref(ctx, unit).or_not_found()
             }
+            Literal::Character(chr) => searcher
+                .search_pos_with_ref(ctx, pos, &chr.reference)
+                .or_not_found(),
             _ => NotFound,
         },
     }
@@ -1073,6 +1093,20 @@ impl Search for Declaration {
             Declaration::Configuration(_) => {
                 // @TODO
             }
+
+            Declaration::Disconnection(disconnection) => {
+                let DisconnectionSpecification {
+                    signal_list,
+                    type_mark,
+                    after,
+                    span: _,
+                } = disconnection;
+                if let GuardedSignalList::Names(names) = signal_list {
+                    return_if_found!(names.search(ctx, searcher));
+                }
+                return_if_found!(type_mark.search(ctx, searcher));
+                return_if_found!(after.search(ctx, searcher));
+            }
         }
         NotFound
     }
@@ -1172,7 +1206,7 @@ impl Search for SubprogramHeader {
 
 impl Search for LibraryClause {
     fn search(&self, ctx: &dyn TokenAccess, searcher: &mut impl Searcher) -> SearchResult {
-        for name in self.name_list.items.iter() {
+        for name in self.name_list.iter() {
             return_if_found!(searcher
                 .search_pos_with_ref(ctx, &name.item.pos, &name.reference)
                 .or_not_found());
@@ -1328,6 +1362,109 @@ impl Search for SubprogramInstantiation {
     }
 }
 
+// Search for the innermost declarative region that contains the cursor
+pub struct RegionAtCursor<'a> {
+    root: &'a DesignRoot,
+    cursor: Position,
+    pub result: Option<EntRef<'a>>,
+}
+
+impl<'a> RegionAtCursor<'a> {
+    pub fn new(root: &'a DesignRoot, cursor: Position) -> Self {
+        RegionAtCursor {
+            root,
+            cursor,
+            result: None,
+        }
+    }
+}
+
+impl<'a> Searcher for RegionAtCursor<'a> {
+    fn search_decl(&mut self, ctx: &dyn TokenAccess, decl: FoundDeclaration) -> SearchState {
+        let span = match decl {
+            FoundDeclaration::Entity(value) => value.get_span(ctx),
+            FoundDeclaration::Architecture(value) => value.get_span(ctx),
+            FoundDeclaration::Package(value) => value.get_span(ctx),
+            FoundDeclaration::PackageBody(value) => value.get_span(ctx),
+            FoundDeclaration::Subprogram(value) => value.get_span(ctx),
+            FoundDeclaration::ConcurrentStatement(value) => match value.statement.item {
+                ConcurrentStatement::Process(ref process) => process.get_span(ctx),
+                ConcurrentStatement::Block(ref block) => block.get_span(ctx),
+                _ => return NotFinished,
+            },
+            _ => return NotFinished,
+        };
+
+        // Regions are visited from the outside in so the last match is the innermost
+        if span.contains(self.cursor) {
+            if let Some(id) = decl.ent_id() {
+                self.result = Some(self.root.get_ent(id));
+            }
+        }
+        NotFinished
+    }
+}
+
+// Search for the context clause and the use clauses of the given regions
+// until the innermost region is found
+pub struct RegionUseClauses<'a> {
+    regions: &'a [EntRef<'a>],
+    pub context_clause: ContextClause,
+    pub use_clauses: Vec<(EntityId, UseClause)>,
+}
+
+impl<'a> RegionUseClauses<'a> {
+    pub fn new(regions: &'a [EntRef<'a>]) -> Self {
+        RegionUseClauses {
+            regions,
+            context_clause: ContextClause::default(),
+            use_clauses: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Searcher for RegionUseClauses<'a> {
+    fn search_decl(&mut self, _ctx: &dyn TokenAccess, decl: FoundDeclaration) -> SearchState {
+        let Some(id) = decl.ent_id() else {
+            return NotFinished;
+        };
+        if !self.regions.iter().any(|region| region.id() == id) {
+            return NotFinished;
+        }
+
+        let declarations = match decl {
+            FoundDeclaration::Architecture(value) => {
+                self.context_clause = value.context_clause.clone();
+                &value.decl
+            }
+            FoundDeclaration::PackageBody(value) => {
+                self.context_clause = value.context_clause.clone();
+                &value.decl
+            }
+            FoundDeclaration::Subprogram(value) => &value.declarations,
+            FoundDeclaration::ConcurrentStatement(value) => match value.statement.item {
+                ConcurrentStatement::Process(ref process) => &process.decl,
+                ConcurrentStatement::Block(ref block) => &block.decl,
+                _ => return NotFinished,
+            },
+            _ => return NotFinished,
+        };
+
+        for declaration in declarations.iter() {
+            if let Declaration::Use(use_clause) = declaration {
+                self.use_clauses.push((id, use_clause.clone()));
+            }
+        }
+
+        // Regions are visited from the outside in so the first region is the innermost
+        if self.regions.first().is_some_and(|region| region.id() == id) {
+            Finished(Found)
+        } else {
+            NotFinished
+        }
+    }
+}
+
 // Search for reference to declaration/definition at cursor
 pub struct ItemAtCursor<'a> {
     root: &'a DesignRoot,
@@ -350,6 +350,96 @@ impl Name {
     }
 }
 
+impl Expression {
+    /// Returns the value of an integer literal such as `1` or `16#ff#`
+    /// None is returned when the value does not fit in an i64
+    pub fn as_integer_literal(&self) -> Option<i64> {
+        if let Expression::Literal(Literal::AbstractLiteral(AbstractLiteral::Integer(value))) = self
+        {
+            i64::try_from(*value).ok()
+        } else {
+            None
+        }
+    }
+
+    pub fn as_real_literal(&self) -> Option<f64> {
+        if let Expression::Literal(Literal::AbstractLiteral(AbstractLiteral::Real(value))) = self {
+            Some(*value)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_string_literal(&self) -> Option<&Latin1String> {
+        if let Expression::Literal(Literal::String(value)) = self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_character_literal(&self) -> Option<u8> {
+        if let Expression::Literal(Literal::Character(value)) = self {
+            Some(value.item)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_bit_string_literal(&self) -> Option<&BitString> {
+        if let Expression::Literal(Literal::BitString(value)) = self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+impl SeverityLevel {
+    fn from_expression(expr: &Expression) -> Option<SeverityLevel> {
+        let Expression::Name(name) = expr else {
+            return None;
+        };
+        let Name::Designator(designator) = name.as_ref() else {
+            return None;
+        };
+        let Designator::Identifier(sym) = &designator.item else {
+            return None;
+        };
+
+        match sym.name_utf8().to_ascii_lowercase().as_str() {
+            "note" => Some(SeverityLevel::Note),
+            "warning" => Some(SeverityLevel::Warning),
+            "error" => Some(SeverityLevel::Error),
+            "failure" => Some(SeverityLevel::Failure),
+            _ => None,
+        }
+    }
+
+    /// The severity level of an optional severity expression, or the default when it is omitted.
+    /// Returns None when the severity is not a literal severity level.
+    fn or_default(severity: Option<&WithPos<Expression>>, default: SeverityLevel) -> Option<Self> {
+        match severity {
+            Some(expr) => SeverityLevel::from_expression(&expr.item),
+            None => Some(default),
+        }
+    }
+}
+
+impl AssertStatement {
+    /// The severity of a failed assertion, `error` when no severity is given
+    pub fn severity_level(&self) -> Option<SeverityLevel> {
+        SeverityLevel::or_default(self.severity.as_ref(), SeverityLevel::Error)
+    }
+}
+
+impl ReportStatement {
+    /// The severity of the report, `note` when no severity is given
+    pub fn severity_level(&self) -> Option<SeverityLevel> {
+        SeverityLevel::or_default(self.severity.as_ref(), SeverityLevel::Note)
+    }
+}
+
 impl CallOrIndexed {
     // During parsing function calls and indexed names are ambiguous
     // Thus we convert function calls to indexed names during the analysis stage
@@ -549,3 +639,91 @@ impl SequentialStatement {
         self.label_typ().is_some()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::{SequentialStatement, SeverityLevel};
+    use crate::syntax::test::Code;
+
+    fn severity_level(code: &str) -> Option<SeverityLevel> {
+        match Code::new(code).sequential_statement().statement.item {
+            SequentialStatement::Assert(assert) => assert.severity_level(),
+            SequentialStatement::Report(report) => report.severity_level(),
+            _ => panic!("Expected assert or report statement"),
+        }
+    }
+
+    #[test]
+    fn implicit_severity_levels() {
+        assert_eq!(severity_level("assert false;"), Some(SeverityLevel::Error));
+        assert_eq!(
+            severity_level("assert false report \"msg\";"),
+            Some(SeverityLevel::Error)
+        );
+        assert_eq!(severity_level("report \"msg\";"), Some(SeverityLevel::Note));
+    }
+
+    #[test]
+    fn explicit_severity_levels() {
+        assert_eq!(
+            severity_level("assert false severity FAILURE;"),
+            Some(SeverityLevel::Failure)
+        );
+        assert_eq!(
+            severity_level("report \"msg\" severity warning;"),
+            Some(SeverityLevel::Warning)
+        );
+        assert_eq!(severity_level("report \"msg\" severity sev;"), None);
+    }
+
+    #[test]
+    fn integer_literal_accessor() {
+        assert_eq!(
+            Code::new("16#ff#").expr().item.as_integer_literal(),
+            Some(255)
+        );
+        assert_eq!(Code::new("1.0").expr().item.as_integer_literal(), None);
+        assert_eq!(Code::new("foo").expr().item.as_integer_literal(), None);
+    }
+
+    #[test]
+    fn real_literal_accessor() {
+        assert_eq!(Code::new("1.5").expr().item.as_real_literal(), Some(1.5));
+        assert_eq!(Code::new("1").expr().item.as_real_literal(), None);
+    }
+
+    #[test]
+    fn string_literal_accessor() {
+        assert_eq!(
+            Code::new("\"abc\"")
+                .expr()
+                .item
+                .as_string_literal()
+                .map(|s| s.to_string()),
+            Some("abc".to_owned())
+        );
+        assert_eq!(Code::new("'a'").expr().item.as_string_literal(), None);
+    }
+
+    #[test]
+    fn character_literal_accessor() {
+        assert_eq!(
+            Code::new("'a'").expr().item.as_character_literal(),
+            Some(b'a')
+        );
+        assert_eq!(Code::new("\"a\"").expr().item.as_character_literal(), None);
+    }
+
+    #[test]
+    fn bit_string_literal_accessor() {
+        assert!(Code::new("x\"ff\"")
+            .expr()
+            .item
+            .as_bit_string_literal()
+            .is_some());
+        assert_eq!(
+            Code::new("\"ff\"").expr().item.as_bit_string_literal(),
+            None
+        );
+    }
+}
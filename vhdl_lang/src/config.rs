@@ -17,11 +17,22 @@ use fnv::FnvHashMap;
 use toml::Value;
 
 use crate::data::*;
+use crate::lint::deprecated::{DeprecatedRule, DeprecatedRules};
+use crate::lint::naming::{IdentifierCase, NamingKind, NamingRules};
+use crate::standard::VHDLStandard;
 
 #[derive(Clone, PartialEq, Eq, Default, Debug)]
 pub struct Config {
     // A map from library name to file name
     libraries: FnvHashMap<String, LibraryConfig>,
+    // The VHDL standard that design files must conform to, if any
+    standard: Option<VHDLStandard>,
+    // The identifier case of each kind of declaration, if checked
+    naming_rules: Option<NamingRules>,
+    // Whether ports of mode buffer are reported, if configured
+    buffer_ports: Option<bool>,
+    // The deprecation rules that are enabled, if configured
+    deprecated_rules: Option<DeprecatedRules>,
 }
 
 #[derive(Clone, PartialEq, Eq, Default, Debug)]
@@ -108,6 +119,60 @@ impl Config {
         let config = string.parse::<Value>().map_err(|err| err.to_string())?;
         let mut libraries = FnvHashMap::default();
 
+        let standard = if let Some(standard) = config.get("standard") {
+            let standard = standard
+                .as_str()
+                .ok_or("standard must be a string")?
+                .parse::<VHDLStandard>()?;
+            Some(standard)
+        } else {
+            None
+        };
+
+        let lint = config
+            .get("lint")
+            .map(|lint| lint.as_table().ok_or("lint must be a table"))
+            .transpose()?;
+
+        let naming_rules = if let Some(cases) = lint.and_then(|lint| lint.get("identifier_case")) {
+            let cases = cases
+                .as_table()
+                .ok_or("lint.identifier_case must be a table")?;
+            let mut rules = NamingRules::default();
+            for (kind, case) in cases.iter() {
+                let case = case
+                    .as_str()
+                    .ok_or_else(|| format!("identifier case of {kind} must be a string"))?;
+                rules.set(kind.parse::<NamingKind>()?, case.parse::<IdentifierCase>()?);
+            }
+            Some(rules)
+        } else {
+            None
+        };
+
+        let buffer_ports = lint
+            .and_then(|lint| lint.get("buffer_ports"))
+            .map(|buffer_ports| {
+                buffer_ports
+                    .as_bool()
+                    .ok_or("lint.buffer_ports must be a boolean")
+            })
+            .transpose()?;
+
+        let deprecated_rules = if let Some(rules) = lint.and_then(|lint| lint.get("deprecated")) {
+            let rules = rules.as_array().ok_or("lint.deprecated must be an array")?;
+            let mut enabled = DeprecatedRules::default();
+            for rule in rules.iter() {
+                let rule = rule
+                    .as_str()
+                    .ok_or_else(|| format!("deprecation rule {rule} must be a string"))?;
+                enabled.enable(rule.parse::<DeprecatedRule>()?);
+            }
+            Some(enabled)
+        } else {
+            None
+        };
+
         let libs = config
             .get("libraries")
             .ok_or("missing field libraries")?
@@ -174,7 +239,13 @@ impl Config {
             );
         }
 
-        Ok(Config { libraries })
+        Ok(Config {
+            libraries,
+            standard,
+            naming_rules,
+            buffer_ports,
+            deprecated_rules,
+        })
     }
 
     pub fn read_file_path(file_name: &Path) -> io::Result<Config> {
@@ -195,6 +266,26 @@ impl Config {
         self.libraries.values()
     }
 
+    /// The configured VHDL standard, if any
+    pub fn standard(&self) -> Option<VHDLStandard> {
+        self.standard
+    }
+
+    /// The configured identifier case rules, if any
+    pub fn naming_rules(&self) -> Option<&NamingRules> {
+        self.naming_rules.as_ref()
+    }
+
+    /// Whether ports of mode buffer shall be reported
+    pub fn buffer_port_detection(&self) -> bool {
+        self.buffer_ports.unwrap_or(false)
+    }
+
+    /// The configured deprecation rules, if any
+    pub fn deprecated_rules(&self) -> Option<&DeprecatedRules> {
+        self.deprecated_rules.as_ref()
+    }
+
     /// Append another config to self
     ///
     /// In case of conflict the appended config takes precedence
@@ -211,6 +302,22 @@ impl Config {
                 self.libraries.insert(library.name.clone(), library.clone());
             }
         }
+
+        if config.standard.is_some() {
+            self.standard = config.standard;
+        }
+
+        if config.naming_rules.is_some() {
+            self.naming_rules = config.naming_rules.clone();
+        }
+
+        if config.buffer_ports.is_some() {
+            self.buffer_ports = config.buffer_ports;
+        }
+
+        if config.deprecated_rules.is_some() {
+            self.deprecated_rules = config.deprecated_rules.clone();
+        }
     }
 
     /// Load configuration file from installation folder
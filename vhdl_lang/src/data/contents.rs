@@ -11,26 +11,85 @@ use std::io;
 use std::io::prelude::Read;
 use std::path::Path;
 
+/// The character encoding of a source file
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Encoding {
+    #[default]
+    Latin1,
+    Utf8,
+}
+
+const UTF8_BOM: &[u8] = b"\xef\xbb\xbf";
+
 pub struct Contents {
     lines: Vec<String>,
+    had_bom: bool,
+    encoding: Encoding,
 }
 
 impl Contents {
     pub fn from_latin1_file(file_name: &Path) -> io::Result<Contents> {
+        Contents::from_file_with_encoding(file_name, Encoding::Latin1)
+    }
+
+    pub fn from_file_with_encoding(file_name: &Path, encoding: Encoding) -> io::Result<Contents> {
         let mut file = File::open(file_name)?;
         let mut bytes = Vec::new();
         file.read_to_end(&mut bytes)?;
-        Ok(Contents::from_str(
-            &Latin1String::from_vec(bytes).to_string(),
-        ))
+
+        // A byte order mark is not part of the code and would shift the positions of the first line
+        let had_bom = bytes.starts_with(UTF8_BOM);
+        if had_bom {
+            bytes.drain(..UTF8_BOM.len());
+        }
+
+        let code = match encoding {
+            Encoding::Latin1 => Latin1String::from_vec(bytes).to_string(),
+            Encoding::Utf8 => String::from_utf8(bytes).map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "Invalid UTF-8 at byte offset {}",
+                        err.utf8_error().valid_up_to()
+                    ),
+                )
+            })?,
+        };
+        let mut contents = Contents::from_str(&code);
+        contents.had_bom = had_bom;
+        contents.encoding = encoding;
+        Ok(contents)
     }
 
     pub fn from_str(code: &str) -> Contents {
         Contents {
             lines: split_lines(code),
+            had_bom: false,
+            encoding: Encoding::default(),
         }
     }
 
+    /// True if a leading UTF-8 byte order mark was stripped when reading the file
+    pub fn had_bom(&self) -> bool {
+        self.had_bom
+    }
+
+    /// The encoding that the file was read with
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// A hash of the contents that is stable between runs
+    #[cfg(feature = "serde")]
+    pub fn content_hash(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = fnv::FnvHasher::default();
+        self.lines.hash(&mut hasher);
+        hasher.finish()
+    }
+
     pub fn start(&self) -> Position {
         Position {
             line: 0,
@@ -67,6 +126,8 @@ impl Contents {
 
         Contents {
             lines: split_lines(&result),
+            had_bom: false,
+            encoding: self.encoding,
         }
     }
 
@@ -350,6 +411,55 @@ mod tests {
         ContentReader::new(contents)
     }
 
+    fn file_with_bytes(bytes: &[u8]) -> tempfile::NamedTempFile {
+        use std::io::Write;
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(bytes).unwrap();
+        file
+    }
+
+    #[test]
+    fn utf8_file_round_trips() {
+        let code = "-- Größe €\nentity ent is\nend entity;\n";
+        let file = file_with_bytes(code.as_bytes());
+        let contents = Contents::from_file_with_encoding(file.path(), Encoding::Utf8).unwrap();
+        let lines: Vec<_> = (0..contents.num_lines())
+            .filter_map(|lineno| contents.get_line(lineno))
+            .collect();
+        assert_eq!(lines.concat(), code);
+    }
+
+    #[test]
+    fn latin1_file_is_the_default() {
+        let file = file_with_bytes(b"-- Gr\xf6\xdfe\n");
+        let contents = Contents::from_latin1_file(file.path()).unwrap();
+        assert_eq!(contents.get_line(0), Some("-- Größe\n"));
+        assert_eq!(Encoding::default(), Encoding::Latin1);
+    }
+
+    #[test]
+    fn bom_is_stripped() {
+        for encoding in [Encoding::Latin1, Encoding::Utf8] {
+            let file = file_with_bytes(b"\xef\xbb\xbfentity ent is\n");
+            let contents = Contents::from_file_with_encoding(file.path(), encoding).unwrap();
+            assert!(contents.had_bom());
+            assert_eq!(contents.get_line(0), Some("entity ent is\n"));
+        }
+
+        let file = file_with_bytes(b"entity ent is\n");
+        assert!(!Contents::from_latin1_file(file.path()).unwrap().had_bom());
+    }
+
+    #[test]
+    fn invalid_utf8_file_is_an_error() {
+        let file = file_with_bytes(b"-- ok\n-- \xf6\n");
+        let err = Contents::from_file_with_encoding(file.path(), Encoding::Utf8)
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "Invalid UTF-8 at byte offset 9");
+    }
+
     #[test]
     fn pop_latin1_ok() {
         let contents = new("hi");
@@ -581,6 +691,39 @@ mod tests {
         assert_eq!(contents.get_line(2).unwrap().to_string(), "...");
     }
 
+    #[test]
+    fn change_equals_fresh_contents() {
+        let mut contents =
+            new("entity ent is\nend entity;\n\narchitecture a of ent is\nbegin\nend;\n");
+        let edits = [
+            (Range::new(Position::new(0, 7), Position::new(0, 10)), "top"),
+            (
+                Range::new(Position::new(3, 18), Position::new(3, 21)),
+                "top",
+            ),
+            (
+                Range::new(Position::new(3, 24), Position::new(4, 0)),
+                "\n  signal s : bit;\n",
+            ),
+            (Range::new(Position::new(1, 0), Position::new(2, 0)), ""),
+            (
+                Range::new(Position::new(5, 3), Position::new(5, 4)),
+                " architecture;",
+            ),
+        ];
+
+        for (range, text) in edits.iter() {
+            contents.change(range, text);
+            let fresh = new(&flatten(&contents));
+            assert_eq!(contents.lines, fresh.lines);
+            assert_eq!(contents.end(), fresh.end());
+        }
+        assert_eq!(
+            flatten(&contents),
+            "entity top is\n\narchitecture a of top is\n  signal s : bit;\nbegin\nend architecture;\n"
+        );
+    }
+
     #[test]
     fn change_empty() {
         let mut contents = new("");
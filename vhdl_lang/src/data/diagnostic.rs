@@ -101,15 +101,42 @@ impl Diagnostic {
             result.push_str(&pos.show(&format!("related: {message}")));
             result.push('\n');
         }
-        let severity = match self.severity {
-            Severity::Error => &"error",
-            Severity::Warning => &"warning",
-            Severity::Info => &"info",
-            Severity::Hint => &"hint",
-        };
-        result.push_str(&self.pos.show(&format!("{}: {}", severity, self.message)));
+        result.push_str(
+            &self
+                .pos
+                .show(&format!("{}: {}", self.severity.as_str(), self.message)),
+        );
         result
     }
+
+    /// The message, severity and positions as JSON for machine readable output
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "message": self.message,
+            "severity": self.severity.as_str(),
+            "span": self.pos.to_json(),
+            "related": self
+                .related
+                .iter()
+                .map(|(pos, message)| serde_json::json!({
+                    "message": message,
+                    "span": pos.to_json(),
+                }))
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+            Severity::Hint => "hint",
+        }
+    }
 }
 
 pub type DiagnosticResult<T> = Result<T, Diagnostic>;
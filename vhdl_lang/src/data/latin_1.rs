@@ -104,6 +104,28 @@ impl Latin1String {
         latin1
     }
 
+    pub fn uppercase(chr: u8) -> u8 {
+        match chr {
+            b'a'..=b'z' | 224..=246 | 248..=254 => chr - 32,
+            _ => chr,
+        }
+    }
+
+    pub fn to_uppercase(&self) -> Latin1String {
+        Latin1String {
+            bytes: self.bytes.iter().map(|chr| Self::uppercase(*chr)).collect(),
+        }
+    }
+
+    /// Compare as VHDL identifiers where letters of the whole Latin-1 range are case-insensitive
+    pub fn eq_ignore_ascii_case(&self, other: &Latin1String) -> bool {
+        self.len() == other.len()
+            && self
+                .chars()
+                .zip(other.chars())
+                .all(|(a, b)| Self::lowercase(*a) == Self::lowercase(*b))
+    }
+
     pub fn starts_with(&self, other: &Latin1String) -> bool {
         if other.len() <= self.len() {
             self.bytes[0..other.len()] == other.bytes
@@ -226,6 +248,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn latin1_uppercase() {
+        for byte in 0..=255 {
+            let latin1 = Latin1String::new(&[byte]);
+            let utf8 = latin1.to_string().to_uppercase();
+            // Letters such as ß, µ and ÿ have no uppercase within Latin-1
+            let expected = Latin1String::from_utf8(&utf8)
+                .ok()
+                .filter(|upper| upper.len() == 1)
+                .unwrap_or_else(|| latin1.clone());
+            assert_eq!(latin1.to_uppercase(), expected);
+        }
+    }
+
+    #[test]
+    fn latin1_uppercase_leaves_division_and_multiplication_sign() {
+        assert_eq!(Latin1String::uppercase(0xF7), 0xF7);
+        assert_eq!(Latin1String::lowercase(0xD7), 0xD7);
+        assert_eq!(Latin1String::uppercase(0xE5), 0xC5);
+        assert_eq!(Latin1String::lowercase(0xC5), 0xE5);
+    }
+
+    #[test]
+    fn latin1_eq_ignore_ascii_case() {
+        let foo = Latin1String::from_utf8_unchecked("Foo_Å");
+        assert!(foo.eq_ignore_ascii_case(&Latin1String::from_utf8_unchecked("foo_å")));
+        assert!(foo.eq_ignore_ascii_case(&Latin1String::from_utf8_unchecked("FOO_Å")));
+        assert!(!foo.eq_ignore_ascii_case(&Latin1String::from_utf8_unchecked("foo_a")));
+        assert!(!foo.eq_ignore_ascii_case(&Latin1String::from_utf8_unchecked("foo_åå")));
+        assert!(!Latin1String::new(&[0xD7]).eq_ignore_ascii_case(&Latin1String::new(&[0xF7])));
+    }
+
     #[test]
     fn utf8_to_latin1() {
         let utf8 = "åäö";
@@ -4,7 +4,8 @@
 //
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 
-use super::contents::Contents;
+use super::contents::{Contents, Encoding};
+use super::diagnostic::Severity;
 use parking_lot::{RwLock, RwLockReadGuard};
 use std::cmp::{max, min};
 use std::collections::hash_map::DefaultHasher;
@@ -70,8 +71,8 @@ impl UniqueSource {
         }
     }
 
-    fn from_latin1_file(file_name: &Path) -> io::Result<Self> {
-        let contents = Contents::from_latin1_file(file_name)?;
+    fn from_file_with_encoding(file_name: &Path, encoding: Encoding) -> io::Result<Self> {
+        let contents = Contents::from_file_with_encoding(file_name, encoding)?;
         Ok(Self {
             file_id: FileId::new(file_name),
             contents: RwLock::new(contents),
@@ -134,6 +135,8 @@ impl Hash for Source {
 
 impl Source {
     /// Creates a source from a (virtual) name and in-memory contents.
+    /// The name may refer to a file on disk whose contents differ,
+    /// such as an unsaved editor buffer.
     ///
     /// Note: For differing values of `contents`, the value of `file_name`
     /// *must* differ as well.
@@ -144,8 +147,12 @@ impl Source {
     }
 
     pub fn from_latin1_file(file_name: &Path) -> io::Result<Source> {
+        Self::from_file_with_encoding(file_name, Encoding::Latin1)
+    }
+
+    pub fn from_file_with_encoding(file_name: &Path, encoding: Encoding) -> io::Result<Source> {
         Ok(Source {
-            source: Arc::new(UniqueSource::from_latin1_file(file_name)?),
+            source: Arc::new(UniqueSource::from_file_with_encoding(file_name, encoding)?),
         })
     }
 
@@ -160,6 +167,11 @@ impl Source {
         self.source.contents()
     }
 
+    /// True if a leading UTF-8 byte order mark was stripped when reading the file
+    pub fn had_bom(&self) -> bool {
+        self.contents().had_bom()
+    }
+
     pub fn file_name(&self) -> &Path {
         self.source.file_name()
     }
@@ -176,6 +188,10 @@ impl Source {
     }
 
     pub fn change(&self, range: Option<&Range>, content: &str) {
+        // The content hash of a serialized source no longer applies
+        #[cfg(feature = "serde")]
+        SERDE_SOURCES.lock().remove(self.file_name());
+
         let mut contents = self.source.contents.write();
         if let Some(range) = range {
             contents.change(range, content);
@@ -185,8 +201,92 @@ impl Source {
     }
 }
 
+/// Sources that have been serialized or deserialized by file name together with their content hash.
+/// Positions within the same file share one source and the file is read at most once.
+#[cfg(feature = "serde")]
+static SERDE_SOURCES: std::sync::LazyLock<parking_lot::Mutex<SerdeSources>> =
+    std::sync::LazyLock::new(Default::default);
+
+#[cfg(feature = "serde")]
+type SerdeSources = fnv::FnvHashMap<PathBuf, (u64, std::sync::Weak<UniqueSource>)>;
+
+/// The serialized form of a [`Source`].
+/// The contents are referenced by hash. They are taken from a source with the same file name
+/// that is still alive, or else read from the file again with the same encoding on deserialization.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerializedSource {
+    file_name: PathBuf,
+    encoding: Encoding,
+    content_hash: u64,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Source {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let file_name = self.file_name().to_owned();
+        let content_hash = {
+            let mut sources = SERDE_SOURCES.lock();
+            match sources.get(&file_name) {
+                Some((hash, source)) if std::ptr::eq(source.as_ptr(), &*self.source) => *hash,
+                _ => {
+                    let hash = self.contents().content_hash();
+                    sources.insert(file_name.clone(), (hash, Arc::downgrade(&self.source)));
+                    hash
+                }
+            }
+        };
+
+        SerializedSource {
+            file_name,
+            encoding: self.contents().encoding(),
+            content_hash,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Source {
+    /// Fails if the file cannot be read or if its contents have changed since serialization
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let serialized = SerializedSource::deserialize(deserializer)?;
+        let mut sources = SERDE_SOURCES.lock();
+
+        if let Some((hash, source)) = sources.get(&serialized.file_name) {
+            if let Some(source) = source.upgrade() {
+                if *hash == serialized.content_hash {
+                    return Ok(Source { source });
+                }
+            }
+        }
+
+        let source = Source::from_file_with_encoding(&serialized.file_name, serialized.encoding)
+            .map_err(|err| {
+                D::Error::custom(format!(
+                    "Could not read {}: {err}",
+                    serialized.file_name.to_string_lossy()
+                ))
+            })?;
+
+        let hash = source.contents().content_hash();
+        if hash != serialized.content_hash {
+            return Err(D::Error::custom(format!(
+                "Contents of {} have changed",
+                serialized.file_name.to_string_lossy()
+            )));
+        }
+
+        sources.insert(serialized.file_name, (hash, Arc::downgrade(&source.source)));
+        Ok(source)
+    }
+}
+
 /// A lexical position (line, column) in a source.
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Position {
     /// Line (zero-based).
     pub line: u32,
@@ -234,6 +334,7 @@ impl Position {
 
 /// A lexical range in a source.
 #[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Range {
     /// Start of the range (inclusive).
     pub start: Position,
@@ -253,6 +354,7 @@ impl Range {
 
 /// A lexical range within a specific source file.
 #[derive(PartialEq, Clone, Debug, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SrcPos {
     /// The referenced source file.
     pub source: Source,
@@ -344,6 +446,12 @@ impl<T> From<WithPos<T>> for SrcPos {
     }
 }
 
+const ANSI_RED: &str = "\x1b[31m";
+const ANSI_YELLOW: &str = "\x1b[33m";
+const ANSI_BLUE: &str = "\x1b[34m";
+const ANSI_DIM: &str = "\x1b[2m";
+const ANSI_RESET: &str = "\x1b[0m";
+
 impl SrcPos {
     const LINE_CONTEXT: u32 = 2;
 
@@ -351,11 +459,29 @@ impl SrcPos {
         SrcPos { source, range }
     }
 
-    fn get_line_context(&self, context_lines: u32, contents: &Contents) -> Vec<(u32, String)> {
+    /// The lines of the position and of the related positions with `context_lines` lines
+    /// before the first and after the last of them
+    fn get_line_context(
+        &self,
+        related: &[(&SrcPos, &str)],
+        context_lines: u32,
+        contents: &Contents,
+    ) -> Vec<(u32, String)> {
         let mut lines = Vec::new();
 
-        let start = self.range.start.line.saturating_sub(context_lines);
-        let end = self.range.end.line + context_lines;
+        let first_line = related
+            .iter()
+            .map(|(pos, _)| pos.range.start.line)
+            .fold(self.range.start.line, min);
+        let last_line = related
+            .iter()
+            .map(|(pos, _)| pos.range.end.line)
+            .fold(self.range.end.line, max);
+
+        let start = first_line.saturating_sub(context_lines);
+        let end = last_line
+            .saturating_add(context_lines)
+            .min(contents.num_lines() as u32);
 
         for lineno in start..=end {
             if let Some(line) = contents.get_line(lineno as usize) {
@@ -364,11 +490,15 @@ impl SrcPos {
         }
 
         if lines.is_empty() {
-            lines.push((self.range.start.line, String::new()));
+            lines.push((first_line, String::new()));
         }
         lines
     }
 
+    fn overlaps_line(&self, lineno: u32) -> bool {
+        self.range.start.line <= lineno && lineno <= self.range.end.line
+    }
+
     fn push_replicate(line: &mut String, chr: char, times: usize) {
         for _ in 0..times {
             line.push(chr);
@@ -383,8 +513,32 @@ impl SrcPos {
         }
     }
 
+    /// The line cut to at most `max_width` characters and whether it was cut
+    fn truncate_line(line: &str, max_width: Option<usize>) -> (&str, bool) {
+        match max_width.and_then(|max_width| line.char_indices().nth(max_width)) {
+            Some((idx, _)) => (&line[..idx], true),
+            None => (line, false),
+        }
+    }
+
+    /// Wrap text in an ANSI color escape sequence if a color is given
+    fn paint(text: &str, color: Option<&str>) -> String {
+        match color {
+            Some(color) if !text.is_empty() => format!("{color}{text}{ANSI_RESET}"),
+            _ => text.to_owned(),
+        }
+    }
+
     /// Write ~~~ to underline symbol
-    fn underline(&self, lineno_len: usize, lineno: u32, line: &str, into: &mut String) {
+    fn underline(
+        &self,
+        lineno_len: usize,
+        lineno: u32,
+        line: &str,
+        is_truncated: bool,
+        color: Option<&str>,
+        into: &mut String,
+    ) {
         const NEWLINE_SIZE: usize = 1;
         into.reserve("  |  ".len() + lineno_len + line.len() + NEWLINE_SIZE);
 
@@ -399,52 +553,70 @@ impl SrcPos {
             character: 0,
         };
         // Padding before underline
+        let mut tildes = String::new();
         for chr in line.chars() {
             if pos < self.range.start {
                 Self::push_replicate(into, ' ', Self::visual_width(chr));
             } else if pos < self.range.end {
-                Self::push_replicate(into, '~', Self::visual_width(chr));
+                Self::push_replicate(&mut tildes, '~', Self::visual_width(chr));
             } else {
                 break;
             }
             pos.character += chr.len_utf16() as u32;
         }
 
-        if lineno == self.range.end.line {
+        if lineno == self.range.end.line && !is_truncated {
             while pos < self.range.end {
-                into.push('~');
+                tildes.push('~');
                 pos.character += 1;
             }
         }
+        into.push_str(&Self::paint(&tildes, color));
 
         // Newline
         into.push('\n');
     }
 
+    /// Code context of the position where the related positions within the same source
+    /// are shown in the same snippet, underlined and followed by their label
     fn code_context_from_contents(
         &self,
         contents: &Contents,
         context_lines: u32,
+        max_line_width: Option<usize>,
+        color: Option<&str>,
+        related: &[(&SrcPos, &str)],
     ) -> (usize, String) {
-        let lines = self.get_line_context(context_lines, contents);
+        let lines = self.get_line_context(related, context_lines, contents);
         use pad::{Alignment, PadStr};
+        // Do not let a large context widen the line numbers far beyond the end of the file
+        let last_lineno = related
+            .iter()
+            .map(|(pos, _)| pos.range.start.line)
+            .fold(self.range.start.line, max)
+            .saturating_add(context_lines)
+            .min((contents.num_lines() as u32).saturating_add(Self::LINE_CONTEXT));
         // +1 since lines are shown with 1-index
-        let lineno_len = (self.range.start.line + context_lines + 1)
-            .to_string()
-            .len();
+        let lineno_len = (last_lineno + 1).to_string().len();
 
         let mut result = String::new();
 
         for (lineno, line) in lines.iter() {
             let line = line.to_string();
             let line = line.trim_matches('\n');
+            let (line, is_truncated) = Self::truncate_line(line, max_line_width);
             let lineno_str = (lineno + 1)
                 .to_string()
                 .pad_to_width_with_alignment(lineno_len, Alignment::Right);
-            let overlaps = self.range.start.line <= *lineno && *lineno <= self.range.end.line;
-
-            if overlaps {
-                write!(result, "{lineno_str} --> ").unwrap();
+            let lineno_str = Self::paint(&lineno_str, color.map(|_| ANSI_DIM));
+            let overlaps = self.overlaps_line(*lineno);
+            let overlapping_related: Vec<_> = related
+                .iter()
+                .filter(|(pos, _)| pos.overlaps_line(*lineno))
+                .collect();
+
+            if overlaps || !overlapping_related.is_empty() {
+                write!(result, "{lineno_str} {} ", Self::paint("-->", color)).unwrap();
             } else {
                 write!(result, "{lineno_str}  |  ").unwrap();
             }
@@ -456,41 +628,156 @@ impl SrcPos {
                     result.push(chr);
                 }
             }
+            if is_truncated {
+                result.push_str("...");
+            }
             result.push('\n');
 
             if overlaps {
-                self.underline(lineno_len, *lineno, line, &mut result);
+                self.underline(lineno_len, *lineno, line, is_truncated, color, &mut result);
+            }
+            for (pos, label) in overlapping_related {
+                pos.underline(lineno_len, *lineno, line, is_truncated, color, &mut result);
+                result.pop();
+                writeln!(result, " {label}").unwrap();
             }
         }
 
         (lineno_len, result)
     }
 
+    /// The 0-based visual column and byte offset of a position within the contents.
+    /// Positions past the end of the contents are clamped to the end.
+    #[cfg(feature = "serde")]
+    fn column_and_offset(contents: &Contents, pos: Position) -> (usize, usize) {
+        let pos = min(pos, contents.end());
+        let mut offset: usize = (0..pos.line as usize)
+            .filter_map(|lineno| contents.get_line(lineno))
+            .map(str::len)
+            .sum();
+
+        let mut column = 0;
+        let mut character = 0;
+        for chr in contents.get_line(pos.line as usize).unwrap_or("").chars() {
+            if character >= pos.character {
+                break;
+            }
+            column += Self::visual_width(chr);
+            offset += chr.len_utf8();
+            character += chr.len_utf16() as u32;
+        }
+        (column, offset)
+    }
+
+    /// The file and range as JSON for machine readable output.
+    /// Lines and columns are 1-based where a tab is 4 columns wide,
+    /// byte offsets are 0-based and refer to the contents as read.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> serde_json::Value {
+        let contents = self.source.contents();
+        let start = min(self.range.start, contents.end());
+        let end = min(self.range.end, contents.end());
+        let (start_column, byte_start) = Self::column_and_offset(&contents, start);
+        let (end_column, byte_end) = Self::column_and_offset(&contents, end);
+
+        serde_json::json!({
+            "file": self.source.file_name().to_string_lossy(),
+            "start_line": start.line + 1,
+            "start_column": start_column + 1,
+            "end_line": end.line + 1,
+            "end_column": end_column + 1,
+            "byte_start": byte_start,
+            "byte_length": byte_end.saturating_sub(byte_start),
+        })
+    }
+
+    /// The 1-based line and column of the start of the position.
+    /// The column counts characters as the position does.
+    pub fn line_column(&self) -> (usize, usize) {
+        Self::one_based_line_column(self.range.start)
+    }
+
+    /// The 1-based line and column of the end of the position
+    pub fn end_line_column(&self) -> (usize, usize) {
+        Self::one_based_line_column(self.range.end)
+    }
+
+    fn one_based_line_column(pos: Position) -> (usize, usize) {
+        (pos.line as usize + 1, pos.character as usize + 1)
+    }
+
     /// Create a string for pretty printing.
     pub fn code_context(&self) -> String {
-        self.lineno_len_and_code_context().1
+        self.lineno_len_and_code_context(None, &[]).1
+    }
+
+    /// Create a string for pretty printing with `context_lines` lines shown
+    /// before and after the lines of the position.
+    pub fn code_context_with_lines(&self, context_lines: u32) -> String {
+        let contents = self.source.contents();
+        self.code_context_from_contents(&contents, context_lines, None, None, &[])
+            .1
+    }
+
+    /// Create a string for pretty printing where lines longer than `max_line_width`
+    /// characters are cut and end with an ellipsis.
+    pub fn code_context_limited(&self, max_line_width: usize) -> String {
+        let contents = self.source.contents();
+        self.code_context_from_contents(
+            &contents,
+            Self::LINE_CONTEXT,
+            Some(max_line_width),
+            None,
+            &[],
+        )
+        .1
     }
 
-    fn lineno_len_and_code_context(&self) -> (usize, String) {
+    fn lineno_len_and_code_context(
+        &self,
+        color: Option<&str>,
+        related: &[(&SrcPos, &str)],
+    ) -> (usize, String) {
         let contents = self.source.contents();
-        self.code_context_from_contents(&contents, Self::LINE_CONTEXT)
+        self.code_context_from_contents(&contents, Self::LINE_CONTEXT, None, color, related)
     }
 
     pub fn show(&self, message: &str) -> String {
-        let (lineno_len, pretty_str) = self.lineno_len_and_code_context();
+        self.show_with_color(message, None, &[])
+    }
+
+    /// Like `show` but with ANSI colors for terminals,
+    /// the markers and underline are colored by the severity
+    pub fn show_colored(&self, message: &str, severity: Severity) -> String {
+        let color = match severity {
+            Severity::Error => ANSI_RED,
+            Severity::Warning => ANSI_YELLOW,
+            Severity::Info | Severity::Hint => ANSI_BLUE,
+        };
+        self.show_with_color(message, Some(color), &[])
+    }
+
+    fn show_with_color(
+        &self,
+        message: &str,
+        color: Option<&str>,
+        related: &[(&SrcPos, &str)],
+    ) -> String {
+        let (lineno_len, pretty_str) = self.lineno_len_and_code_context(color, related);
         let file_name = self.source.file_name();
         let mut result = String::new();
 
-        let lineno = self.range.start.line;
+        let (lineno, _) = self.line_column();
         writeln!(result, "{}", &message).unwrap();
         for _ in 0..lineno_len {
             result.push(' ');
         }
         writeln!(
             result,
-            " --> {}:{}",
+            " {} {}:{}",
+            Self::paint("-->", color),
             file_name.to_string_lossy(),
-            lineno + 1
+            lineno
         )
         .unwrap();
         for _ in 0..lineno_len {
@@ -501,6 +788,25 @@ impl SrcPos {
         result
     }
 
+    /// Create a string for pretty printing a primary position together with related positions.
+    /// Related positions that are near the primary position within the same file are shown
+    /// within the same code snippet, others are shown as separate snippets.
+    pub fn show_multi(primary: &SrcPos, related: &[(&SrcPos, &str)], message: &str) -> String {
+        let context_lines = Self::LINE_CONTEXT;
+        let (near, far): (Vec<_>, Vec<_>) = related.iter().partition(|(pos, _)| {
+            pos.source == primary.source
+                && pos.range.start.line <= primary.range.end.line + 2 * context_lines + 1
+                && primary.range.start.line <= pos.range.end.line + 2 * context_lines + 1
+        });
+
+        let mut result = primary.show_with_color(message, None, &near);
+        for (pos, label) in far {
+            result.push('\n');
+            result.push_str(&pos.show(label));
+        }
+        result
+    }
+
     /// Combines two lexical positions into a larger lexical position overlapping both.
     /// The file name is assumed to be the same.
     pub fn combine_into(self, other: &dyn AsRef<Self>) -> Self {
@@ -554,6 +860,13 @@ impl SrcPos {
         self.range.contains(pos)
     }
 
+    /// True if the other position lies within this one and both are in the same source
+    pub fn contains_pos(&self, other: &SrcPos) -> bool {
+        self.source == other.source
+            && self.range.start <= other.range.start
+            && other.range.end <= self.range.end
+    }
+
     pub fn end_pos(&self) -> SrcPos {
         SrcPos::new(self.source.clone(), Range::new(self.end(), self.end()))
     }
@@ -670,6 +983,26 @@ mod tests {
         });
     }
 
+    #[test]
+    fn code_context_pos_from_filename_with_bom() {
+        use std::io::Write;
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(b"\xef\xbb\xbfhello\nworld\n").unwrap();
+        let source = Source::from_file_with_encoding(file.path(), Encoding::Utf8).unwrap();
+        assert!(source.had_bom());
+
+        let code = CodeBuilder::new().code_from_source(source);
+        assert_eq!(code.s1("hello").start(), Position::new(0, 0));
+        assert_eq!(
+            code.s1("hello").pos().code_context(),
+            "\
+1 --> hello
+   |  ~~~~~
+2  |  world
+"
+        );
+    }
+
     #[test]
     fn code_context_pos_last_line_without_newline() {
         let code = Code::new("hello world");
@@ -741,6 +1074,188 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn to_json() {
+        let code = Code::new("entity ent is\n\tport (x : bit);\nend;");
+        let json = code.s1("x : bit").pos().to_json();
+        assert_eq!(json["start_line"], 2);
+        assert_eq!(json["start_column"], 11);
+        assert_eq!(json["end_line"], 2);
+        assert_eq!(json["end_column"], 18);
+        assert_eq!(json["byte_start"], 21);
+        assert_eq!(json["byte_length"], 7);
+
+        // The end is clamped to the end of the contents
+        let past_end = SrcPos::new(
+            code.source().clone(),
+            Range::new(Position::new(2, 0), Position::new(5, 0)),
+        );
+        let json = past_end.to_json();
+        assert_eq!(json["end_line"], 3);
+        assert_eq!(json["end_column"], 5);
+        assert_eq!(json["byte_length"], 4);
+
+        let diagnostic = crate::Diagnostic::error(code.s1("ent is").s1("ent"), "message")
+            .related(code.s1("end"), "related");
+        let json = diagnostic.to_json();
+        assert_eq!(json["message"], "message");
+        assert_eq!(json["severity"], "error");
+        assert_eq!(json["span"]["start_column"], 8);
+        assert_eq!(json["related"][0]["message"], "related");
+        assert_eq!(json["related"][0]["span"]["start_line"], 3);
+    }
+
+    #[test]
+    fn show_colored() {
+        let code = Code::new("\thello world\n");
+        let pos = code.s1("world").pos();
+        let colored = pos.show_colored("message", Severity::Warning);
+        assert!(colored.contains("\x1b[33m-->\x1b[0m"));
+        assert!(colored.contains("        \x1b[33m~~~~~\x1b[0m\n"));
+        assert!(colored.contains("\x1b[2m1\x1b[0m"));
+
+        // Alignment is kept when the escape sequences are removed
+        let stripped = [ANSI_YELLOW, ANSI_DIM, ANSI_RESET]
+            .iter()
+            .fold(colored, |text, escape| text.replace(escape, ""));
+        assert_eq!(stripped, pos.show("message"));
+    }
+
+    #[test]
+    fn line_column() {
+        let code = Code::new("entity ent is\nend;");
+        let pos = code.s1("ent is").s1("ent").pos();
+        assert_eq!(pos.line_column(), (1, 8));
+        assert_eq!(pos.end_line_column(), (1, 11));
+
+        let pos = code.s1("is\nend").pos();
+        assert_eq!(pos.line_column(), (1, 12));
+        assert_eq!(pos.end_line_column(), (2, 4));
+
+        let eof = code.eof_pos();
+        assert_eq!(eof.line_column(), (2, 5));
+        assert!(eof
+            .show("eof")
+            .contains(&format!(":{}\n", eof.line_column().0)));
+    }
+
+    #[test]
+    fn code_context_with_lines() {
+        let code = Code::new("one\ntwo\nthree\nfour\nfive\n");
+        let pos = code.s1("three").pos();
+        assert_eq!(
+            pos.code_context_with_lines(0),
+            "\
+3 --> three
+   |  ~~~~~
+",
+        );
+        assert_eq!(
+            pos.code_context_with_lines(1),
+            "\
+2  |  two
+3 --> three
+   |  ~~~~~
+4  |  four
+",
+        );
+        assert_eq!(pos.code_context_with_lines(2), pos.code_context());
+        assert_eq!(
+            pos.code_context_with_lines(u32::MAX),
+            "\
+1  |  one
+2  |  two
+3 --> three
+   |  ~~~~~
+4  |  four
+5  |  five
+",
+        );
+    }
+
+    #[test]
+    fn code_context_limited_truncates_long_lines() {
+        let code = Code::new("short\nhello world\n");
+        assert_eq!(
+            code.s1("hello").pos().code_context_limited(5),
+            "\
+1  |  short
+2 --> hello...
+   |  ~~~~~
+",
+        );
+        assert_eq!(
+            code.s1("hello world").pos().code_context_limited(8),
+            "\
+1  |  short
+2 --> hello wo...
+   |  ~~~~~~~~
+",
+        );
+        assert_eq!(
+            code.s1("hello").pos().code_context_limited(80),
+            code.s1("hello").pos().code_context()
+        );
+    }
+
+    #[test]
+    fn contains_pos() {
+        let code = Code::new("foo bar baz");
+        let foo_bar = code.s1("foo bar").pos();
+
+        assert!(foo_bar.contains_pos(&foo_bar));
+        assert!(foo_bar.contains_pos(&code.s1("bar").pos()));
+        assert!(!foo_bar.contains_pos(&code.s1("bar baz").pos()));
+        assert!(!code.s1("bar").pos().contains_pos(&foo_bar));
+    }
+
+    #[test]
+    fn contains_pos_zero_length() {
+        let code = Code::new("foo bar");
+        let foo = code.s1("foo").pos();
+
+        assert!(foo.contains_pos(&foo.pos_at_beginning()));
+        assert!(foo.contains_pos(&foo.pos_at_end()));
+        assert!(!foo.contains_pos(&code.s1("bar").pos().pos_at_beginning()));
+        assert!(foo.pos_at_end().contains_pos(&foo.pos_at_end()));
+        assert!(!foo.pos_at_end().contains_pos(&foo));
+    }
+
+    #[test]
+    fn contains_pos_other_source() {
+        let code = Code::new("foo bar");
+        let other = Code::new_with_file_name(Path::new("other.vhd"), "foo bar");
+
+        assert!(!code
+            .s1("foo bar")
+            .pos()
+            .contains_pos(&other.s1("foo").pos()));
+    }
+
+    #[test]
+    fn code_context_crlf_line_endings() {
+        let lf = Code::new("hello\nworld\n").s1("world").pos().code_context();
+        assert_eq!(
+            lf,
+            "\
+1  |  hello
+2 --> world
+   |  ~~~~~
+",
+        );
+        assert_eq!(
+            Code::new("hello\r\nworld\r\n")
+                .s1("world")
+                .pos()
+                .code_context(),
+            lf
+        );
+        with_code_from_file("hello\r\nworld\r\n", |code: Code| {
+            assert_eq!(code.s1("world").pos().code_context(), lf);
+        });
+    }
+
     #[test]
     fn code_context_non_ascii() {
         let code = Code::new("åäö\nåäö\n__å_ä_ö__");
@@ -860,4 +1375,101 @@ Greetings
             )
         );
     }
+
+    #[test]
+    fn show_multi_with_nearby_related() {
+        let code = Code::new("signal foo : bit;\nline\nfoo <= bar;\n");
+        assert_eq!(
+            SrcPos::show_multi(
+                &code.s1("bar").pos(),
+                &[(&code.s1("foo").pos(), "Declared here")],
+                "Greetings"
+            ),
+            format!(
+                "\
+Greetings
+  --> {}:3
+   |
+1 --> signal foo : bit;
+   |         ~~~ Declared here
+2  |  line
+3 --> foo <= bar;
+   |         ~~~
+",
+                code.source().file_name().to_string_lossy()
+            )
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn srcpos_serialization_round_trip() {
+        with_code_from_file("hello\nworld\n", |code: Code| {
+            let pos = code.s1("world").pos();
+            let serialized = serde_json::to_string(&pos).unwrap();
+            let deserialized: SrcPos = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(deserialized, pos);
+            assert_eq!(deserialized.code_context(), pos.code_context());
+        });
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn srcpos_serialization_round_trip_utf8() {
+        use std::io::Write;
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all("-- Größe €\nsignal s : bit;\n".as_bytes())
+            .unwrap();
+
+        let source = Source::from_file_with_encoding(file.path(), Encoding::Utf8).unwrap();
+        let code = CodeBuilder::new().code_from_source(source);
+        let serialized = serde_json::to_string(&code.s1("signal").pos()).unwrap();
+        let context = code.s1("signal").pos().code_context();
+        drop(code);
+
+        // The file is read again and would not match the content hash as Latin-1
+        let deserialized: SrcPos = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.source.contents().encoding(), Encoding::Utf8);
+        assert_eq!(
+            deserialized.source.contents().get_line(0),
+            Some("-- Größe €\n")
+        );
+        assert_eq!(deserialized.code_context(), context);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn srcpos_deserialization_shares_source_with_same_file_name() {
+        let code = Code::new_with_file_name(Path::new("serde_inline.vhd"), "hello\nworld\n");
+        let serialized =
+            serde_json::to_string(&[code.s1("hello").pos(), code.s1("world").pos()]).unwrap();
+        let deserialized: Vec<SrcPos> = serde_json::from_str(&serialized).unwrap();
+
+        // Inline sources have no file to read, the live source is used
+        assert!(Arc::ptr_eq(
+            &deserialized[0].source.source,
+            &code.source().source
+        ));
+        assert!(Arc::ptr_eq(
+            &deserialized[1].source.source,
+            &code.source().source
+        ));
+        assert_eq!(
+            deserialized[1].code_context(),
+            code.s1("world").pos().code_context()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn srcpos_deserialization_fails_when_contents_changed() {
+        with_code_from_file("hello\nworld\n", |code: Code| {
+            let file_name = code.source().file_name().to_owned();
+            let serialized = serde_json::to_string(&code.s1("world").pos()).unwrap();
+            drop(code);
+
+            std::fs::write(&file_name, "goodbye\nworld\n").unwrap();
+            assert!(serde_json::from_str::<SrcPos>(&serialized).is_err());
+        });
+    }
 }
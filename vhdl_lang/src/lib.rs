@@ -18,25 +18,32 @@ mod data;
 mod lint;
 mod named_entity;
 mod project;
+mod standard;
 mod syntax;
 
 mod completion;
 
 pub use crate::config::Config;
 pub use crate::data::{
-    Diagnostic, Latin1String, Message, MessageHandler, MessagePrinter, MessageType,
+    Diagnostic, Encoding, Latin1String, Message, MessageHandler, MessagePrinter, MessageType,
     NullDiagnostics, NullMessages, Position, Range, Severity, Source, SrcPos,
 };
 
-pub use crate::analysis::EntHierarchy;
+pub use crate::analysis::{EntHierarchy, ResolvedName, SymbolUnderCursor};
+pub use crate::lint::deprecated::{DeprecatedRule, DeprecatedRules};
+pub use crate::lint::naming::{IdentifierCase, NamingKind, NamingRules};
 pub use crate::named_entity::{
     AnyEnt, AnyEntKind, Concurrent, Design, EntRef, EntityId, HasEntityId, InterfaceEnt, Object,
     Overloaded, Reference, Related, Sequential, Type,
 };
 
-pub use crate::project::{Project, SourceFile};
+pub use crate::project::{Phase, Project, SourceFile, TimingReport};
+pub use crate::standard::{
+    find_versioned_constructs, required_standard, VHDLStandard, VersionedConstruct,
+};
 pub use crate::syntax::{
-    kind_str, HasTokenSpan, ParserResult, Token, TokenAccess, TokenId, TokenSpan, VHDLParser,
+    kind_str, DesignUnit, HasTokenSpan, Kind, Lexer, ParserResult, Token, TokenAccess, TokenId,
+    TokenSpan, VHDLParser,
 };
 
 pub use completion::{list_completion_options, CompletionItem};
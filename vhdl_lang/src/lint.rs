@@ -4,4 +4,150 @@
 //
 // Copyright (c) 2022, Olof Kraigher olof.kraigher@gmail.com
 
+pub mod buffer_port;
 pub mod dead_code;
+pub mod deprecated;
+pub mod naming;
+
+use crate::analysis::DesignRoot;
+use crate::analysis::Library;
+use crate::analysis::LockedUnit;
+use crate::ast::search::FoundDeclaration;
+use crate::ast::search::Search;
+use crate::ast::search::SearchState;
+use crate::ast::search::Searcher;
+use crate::ast::UnitId;
+use crate::data::DiagnosticHandler;
+use crate::data::Symbol;
+use crate::named_entity::HasEntityId;
+use crate::syntax::TokenAccess;
+use crate::Config;
+use crate::Diagnostic;
+use crate::EntRef;
+use fnv::FnvHashMap;
+
+fn search_unit(unit: &LockedUnit, searcher: &mut impl Searcher) {
+    let _ = unit.unit.write().search(&unit.tokens, searcher);
+}
+
+/// Search a primary unit and all of its secondary units
+fn search_design_unit(lib: &Library, primary_unit_name: &Symbol, searcher: &mut impl Searcher) {
+    if let Some(unit) = lib.primary_unit(primary_unit_name) {
+        search_unit(unit, searcher);
+    }
+
+    for unit in lib.secondary_units(primary_unit_name) {
+        search_unit(unit, searcher);
+    }
+}
+
+struct DeclarationSearcher<'a> {
+    root: &'a DesignRoot,
+    declarations: Vec<EntRef<'a>>,
+}
+
+impl<'a> Searcher for DeclarationSearcher<'a> {
+    fn search_decl(&mut self, _ctx: &dyn TokenAccess, decl: FoundDeclaration) -> SearchState {
+        if let Some(id) = decl.ent_id() {
+            self.declarations.push(self.root.get_ent(id));
+        }
+        SearchState::NotFinished
+    }
+}
+
+/// Find all declarations within a design unit and its secondary units
+fn find_declarations<'a>(
+    root: &'a DesignRoot,
+    lib: &Library,
+    primary_unit_name: &Symbol,
+) -> Vec<EntRef<'a>> {
+    let mut searcher = DeclarationSearcher {
+        root,
+        declarations: Vec::new(),
+    };
+    search_design_unit(lib, primary_unit_name, &mut searcher);
+    searcher.declarations
+}
+
+/// Use a struct to keep state of units that do not need to be re-scanned
+#[derive(Default)]
+struct CachedLinter {
+    // library name, primary name
+    diagnostics: FnvHashMap<(Symbol, Symbol), Vec<Diagnostic>>,
+}
+
+impl CachedLinter {
+    /// Re-compute the diagnostics of the analyzed units using `find`
+    /// and report the diagnostics of all units that are not third party
+    fn lint(
+        &mut self,
+        root: &DesignRoot,
+        config: &Config,
+        analyzed_units: &[UnitId],
+        diagnostics: &mut dyn DiagnosticHandler,
+        find: impl Fn(&Library, &Symbol) -> Vec<Diagnostic>,
+    ) {
+        // Prune diagnostics that need to be re-computed
+        for unit in analyzed_units {
+            let key = (unit.library_name().clone(), unit.primary_name().clone());
+            self.diagnostics.remove(&key);
+        }
+
+        // Prune diagnostics for units that no longer exist
+        self.diagnostics.retain(|(library_name, primary_name), _| {
+            if let Some(library) = root.get_lib(library_name) {
+                if library.primary_unit(primary_name).is_some() {
+                    return true;
+                }
+            }
+            false
+        });
+
+        for unit in analyzed_units {
+            let key = (unit.library_name().clone(), unit.primary_name().clone());
+
+            if let Some(library) = root.get_lib(unit.library_name()) {
+                self.diagnostics
+                    .entry(key)
+                    .or_insert_with(|| find(library, unit.primary_name()));
+            }
+        }
+
+        for ((library_name, _), unit_diagnostics) in self.diagnostics.iter() {
+            if let Some(library_config) = config.get_library(&library_name.name_utf8()) {
+                if !library_config.is_third_party {
+                    diagnostics.append(unit_diagnostics.iter().cloned());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::analysis::tests::LibraryBuilder;
+    use crate::ast::PrimaryKind;
+    use crate::syntax::test::check_no_diagnostics;
+    use std::path::Path;
+
+    /// Analyze the code of the builder and lint entity `ent` of library `libname`
+    pub fn lint_entity(
+        builder: LibraryBuilder,
+        lint: impl FnOnce(&DesignRoot, &Config, &[UnitId], &mut dyn DiagnosticHandler),
+    ) -> Vec<Diagnostic> {
+        let (root, diagnostics) = builder.get_analyzed_root();
+        check_no_diagnostics(&diagnostics);
+
+        let config = Config::from_str("[libraries]\nlibname.files = []", Path::new(".")).unwrap();
+        let analyzed_units = vec![UnitId::primary(
+            &root.symbol_utf8("libname"),
+            PrimaryKind::Entity,
+            &root.symbol_utf8("ent"),
+        )];
+
+        let mut diagnostics = Vec::new();
+        lint(&root, &config, &analyzed_units, &mut diagnostics);
+        diagnostics
+    }
+}
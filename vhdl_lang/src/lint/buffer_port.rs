@@ -0,0 +1,109 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::{find_declarations, CachedLinter};
+use crate::analysis::DesignRoot;
+use crate::analysis::Library;
+use crate::ast::Mode;
+use crate::ast::UnitId;
+use crate::data::DiagnosticHandler;
+use crate::data::Symbol;
+use crate::named_entity::ObjectInterface;
+use crate::AnyEntKind;
+use crate::Config;
+use crate::Diagnostic;
+use crate::EntRef;
+
+fn is_buffer_port(ent: EntRef) -> bool {
+    matches!(
+        ent.kind(),
+        AnyEntKind::Object(obj) if matches!(obj.iface, Some(ObjectInterface::Port(Mode::Buffer)))
+    )
+}
+
+/// Find ports of mode buffer within a design unit and its secondary units
+fn find_buffer_ports<'a>(
+    root: &'a DesignRoot,
+    lib: &Library,
+    primary_unit_name: &Symbol,
+) -> Vec<EntRef<'a>> {
+    find_declarations(root, lib, primary_unit_name)
+        .into_iter()
+        .filter(|ent| is_buffer_port(ent))
+        .collect()
+}
+
+#[derive(Default)]
+pub(crate) struct BufferPortLinter {
+    cache: CachedLinter,
+}
+
+impl BufferPortLinter {
+    pub fn lint(
+        &mut self,
+        root: &DesignRoot,
+        config: &Config,
+        analyzed_units: &[UnitId],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        self.cache
+            .lint(root, config, analyzed_units, diagnostics, |library, name| {
+                find_buffer_ports(root, library, name)
+                    .into_iter()
+                    .filter_map(|ent| {
+                        Some(Diagnostic::warning(
+                            ent.decl_pos()?,
+                            format!(
+                                "Port '{}' has mode buffer, consider mode out together with an internal signal",
+                                ent.designator()
+                            ),
+                        ))
+                    })
+                    .collect()
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::tests::LibraryBuilder;
+    use crate::lint::test::lint_entity;
+    use crate::syntax::test::check_diagnostics;
+
+    #[test]
+    fn warns_on_buffer_port_but_not_on_out_port() {
+        let mut builder = LibraryBuilder::new();
+
+        let code = builder.code(
+            "libname",
+            "
+entity ent is
+  port (
+    clk : in bit;
+    q : buffer bit;
+    q_n : out bit
+  );
+end entity;
+
+architecture a of ent is
+begin
+end architecture;",
+        );
+
+        let diagnostics = lint_entity(builder, |root, config, units, diagnostics| {
+            BufferPortLinter::default().lint(root, config, units, diagnostics)
+        });
+
+        check_diagnostics(
+            diagnostics,
+            vec![Diagnostic::warning(
+                code.s1("q : buffer").s1("q"),
+                "Port 'q' has mode buffer, consider mode out together with an internal signal",
+            )],
+        );
+    }
+}
@@ -4,10 +4,9 @@
 //
 // Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
 
+use super::{search_design_unit, CachedLinter};
 use crate::analysis::DesignRoot;
 use crate::analysis::Library;
-use crate::analysis::LockedUnit;
-use crate::ast::search::Search;
 use crate::ast::search::SearchState;
 use crate::ast::search::Searcher;
 use crate::ast::UnitId;
@@ -22,7 +21,6 @@ use crate::Diagnostic;
 use crate::EntRef;
 use crate::Overloaded;
 use crate::SrcPos;
-use fnv::FnvHashMap;
 use fnv::FnvHashSet;
 use itertools::Itertools;
 
@@ -71,10 +69,6 @@ impl<'a> Searcher for DeadCodeSearcher<'a> {
     }
 }
 
-fn search_unit(unit: &LockedUnit, searcher: &mut impl Searcher) {
-    let _ = unit.unit.write().search(&unit.tokens, searcher);
-}
-
 fn is_package_header(ent: EntRef) -> bool {
     matches!(
         ent.kind(),
@@ -168,14 +162,7 @@ fn find_unused_declarations<'a>(
     primary_unit_name: &Symbol,
 ) -> FnvHashSet<EntRef<'a>> {
     let mut searcher = DeadCodeSearcher::new(root);
-
-    if let Some(unit) = lib.primary_unit(primary_unit_name) {
-        search_unit(unit, &mut searcher);
-    }
-
-    for unit in lib.secondary_units(primary_unit_name) {
-        search_unit(unit, &mut searcher);
-    }
+    search_design_unit(lib, primary_unit_name, &mut searcher);
 
     searcher
         .declarations
@@ -194,11 +181,9 @@ fn find_unused_declarations<'a>(
         .collect()
 }
 
-/// Use a struct to keep state of units that do not need to be re-scanned
 #[derive(Default)]
 pub(crate) struct UnusedDeclarationsLinter {
-    // library name, primary name
-    diagnostics: FnvHashMap<(Symbol, Symbol), Vec<Diagnostic>>,
+    cache: CachedLinter,
 }
 
 impl UnusedDeclarationsLinter {
@@ -209,47 +194,23 @@ impl UnusedDeclarationsLinter {
         analyzed_units: &[UnitId],
         diagnostics: &mut dyn DiagnosticHandler,
     ) {
-        // Prune diagnostics that need to be re-computed
-        for unit in analyzed_units {
-            let key = (unit.library_name().clone(), unit.primary_name().clone());
-            self.diagnostics.remove(&key);
-        }
-
-        // Prune diagnostics for units that no longer exist
-        self.diagnostics.retain(|(library_name, primary_name), _| {
-            if let Some(library) = root.get_lib(library_name) {
-                if library.primary_unit(primary_name).is_some() {
-                    return true;
-                }
-            }
-            false
-        });
-
-        for unit in analyzed_units {
-            let key = (unit.library_name().clone(), unit.primary_name().clone());
-
-            if let Some(library) = root.get_lib(unit.library_name()) {
-                self.diagnostics.entry(key).or_insert_with(|| {
-                    find_unused_declarations(root, library, unit.primary_name())
-                        .into_iter()
-                        .filter_map(|ent| {
-                            Some(Diagnostic::warning(
-                                ent.decl_pos()?,
-                                format!("Unused declaration of {}", ent.describe()),
-                            ))
-                        })
-                        .collect_vec()
-                });
-            }
-        }
-
-        for ((library_name, _), unit_diagnostics) in self.diagnostics.iter() {
-            if let Some(library_config) = config.get_library(&library_name.name_utf8()) {
-                if !library_config.is_third_party {
-                    diagnostics.append(unit_diagnostics.iter().cloned());
-                }
-            }
-        }
+        self.cache.lint(
+            root,
+            config,
+            analyzed_units,
+            diagnostics,
+            |library, name| {
+                find_unused_declarations(root, library, name)
+                    .into_iter()
+                    .filter_map(|ent| {
+                        Some(Diagnostic::warning(
+                            ent.decl_pos()?,
+                            format!("Unused declaration of {}", ent.describe()),
+                        ))
+                    })
+                    .collect_vec()
+            },
+        );
     }
 }
 
@@ -0,0 +1,278 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::{search_design_unit, CachedLinter};
+use crate::analysis::DesignRoot;
+use crate::analysis::Library;
+use crate::ast::search::FoundDeclaration;
+use crate::ast::search::SearchState;
+use crate::ast::search::Searcher;
+use crate::ast::ConcurrentStatement;
+use crate::ast::Designator;
+use crate::ast::UnitId;
+use crate::data::DiagnosticHandler;
+use crate::data::SrcPos;
+use crate::data::Symbol;
+use crate::named_entity::Reference;
+use crate::syntax::TokenAccess;
+use crate::AnyEntKind;
+use crate::Config;
+use crate::Diagnostic;
+use crate::EntRef;
+use fnv::FnvHashSet;
+use std::str::FromStr;
+
+/// Legacy constructs that a project may discourage by policy
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DeprecatedRule {
+    /// The `bit` and `bit_vector` types where `std_logic` is preferred
+    BitTypes,
+    /// A process that is suspended by wait statements instead of a sensitivity list
+    ProcessWithoutSensitivityList,
+}
+
+impl FromStr for DeprecatedRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "bit_types" => Ok(DeprecatedRule::BitTypes),
+            "process_without_sensitivity_list" => Ok(DeprecatedRule::ProcessWithoutSensitivityList),
+            _ => Err(format!("'{s}' is not a supported deprecation rule")),
+        }
+    }
+}
+
+/// The deprecation rules that are enabled.
+/// No rule is enabled by default.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DeprecatedRules {
+    enabled: FnvHashSet<DeprecatedRule>,
+}
+
+impl DeprecatedRules {
+    pub fn enable(&mut self, rule: DeprecatedRule) {
+        self.enabled.insert(rule);
+    }
+
+    pub fn is_enabled(&self, rule: DeprecatedRule) -> bool {
+        self.enabled.contains(&rule)
+    }
+}
+
+// Symbols keep the spelling of the declaration, std.standard is written in upper case
+fn is_named(designator: &Designator, name: &str) -> bool {
+    match designator {
+        Designator::Identifier(sym) => sym.name_utf8().eq_ignore_ascii_case(name),
+        _ => false,
+    }
+}
+
+/// The preferred replacement if the entity is the `bit` or `bit_vector` type of `std.standard`
+fn bit_type_replacement(ent: EntRef) -> Option<&'static str> {
+    if !matches!(ent.kind(), AnyEntKind::Type(_)) {
+        return None;
+    }
+
+    let parent = ent.parent?;
+    if !is_named(parent.designator(), "standard")
+        || !parent
+            .library_name()
+            .is_some_and(|sym| sym.name_utf8().eq_ignore_ascii_case("std"))
+    {
+        return None;
+    }
+
+    if is_named(ent.designator(), "bit") {
+        Some("std_logic")
+    } else if is_named(ent.designator(), "bit_vector") {
+        Some("std_logic_vector")
+    } else {
+        None
+    }
+}
+
+struct DeprecatedSearcher<'a> {
+    root: &'a DesignRoot,
+    rules: &'a DeprecatedRules,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Searcher for DeprecatedSearcher<'a> {
+    fn search_pos_with_ref(
+        &mut self,
+        _ctx: &dyn TokenAccess,
+        pos: &SrcPos,
+        reference: &Reference,
+    ) -> SearchState {
+        if self.rules.is_enabled(DeprecatedRule::BitTypes) {
+            if let Some(id) = reference.get() {
+                let ent = self.root.get_ent(id);
+                if let Some(replacement) = bit_type_replacement(ent) {
+                    self.diagnostics.push(Diagnostic::hint(
+                        pos,
+                        format!(
+                            "{} is deprecated by policy, consider {replacement}",
+                            ent.describe()
+                        ),
+                    ));
+                }
+            }
+        }
+        SearchState::NotFinished
+    }
+
+    fn search_decl(&mut self, _ctx: &dyn TokenAccess, decl: FoundDeclaration) -> SearchState {
+        if self
+            .rules
+            .is_enabled(DeprecatedRule::ProcessWithoutSensitivityList)
+        {
+            if let FoundDeclaration::ConcurrentStatement(stmt) = decl {
+                if let ConcurrentStatement::Process(ref process) = stmt.statement.item {
+                    if process.sensitivity_list.is_none() {
+                        let pos = stmt
+                            .label
+                            .tree
+                            .as_ref()
+                            .map(|label| &label.pos)
+                            .unwrap_or(&stmt.statement.pos);
+                        self.diagnostics.push(Diagnostic::hint(
+                            pos,
+                            "Process without sensitivity list is deprecated by policy, consider a sensitivity list",
+                        ));
+                    }
+                }
+            }
+        }
+        SearchState::NotFinished
+    }
+}
+
+/// Find deprecated constructs within a design unit and its secondary units
+fn find_deprecated_constructs(
+    root: &DesignRoot,
+    lib: &Library,
+    primary_unit_name: &Symbol,
+    rules: &DeprecatedRules,
+) -> Vec<Diagnostic> {
+    let mut searcher = DeprecatedSearcher {
+        root,
+        rules,
+        diagnostics: Vec::new(),
+    };
+    search_design_unit(lib, primary_unit_name, &mut searcher);
+
+    searcher.diagnostics
+}
+
+#[derive(Default)]
+pub(crate) struct DeprecatedConstructLinter {
+    rules: DeprecatedRules,
+    cache: CachedLinter,
+}
+
+impl DeprecatedConstructLinter {
+    pub fn new(rules: DeprecatedRules) -> Self {
+        Self {
+            rules,
+            cache: Default::default(),
+        }
+    }
+
+    pub fn lint(
+        &mut self,
+        root: &DesignRoot,
+        config: &Config,
+        analyzed_units: &[UnitId],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let rules = &self.rules;
+        self.cache.lint(
+            root,
+            config,
+            analyzed_units,
+            diagnostics,
+            |library, name| find_deprecated_constructs(root, library, name, rules),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::tests::LibraryBuilder;
+    use crate::lint::test::lint_entity;
+    use crate::syntax::test::{check_diagnostics, check_no_diagnostics};
+
+    fn lint(rules: DeprecatedRules, builder: LibraryBuilder) -> Vec<Diagnostic> {
+        let mut linter = DeprecatedConstructLinter::new(rules);
+        lint_entity(builder, |root, config, units, diagnostics| {
+            linter.lint(root, config, units, diagnostics)
+        })
+    }
+
+    const CODE: &str = "
+entity ent is
+  port (
+    data : in bit_vector(7 downto 0);
+    valid : in boolean
+  );
+end entity;
+
+architecture a of ent is
+begin
+  waiting: process
+  begin
+    wait on valid;
+  end process;
+
+  process (valid)
+  begin
+  end process;
+end architecture;";
+
+    #[test]
+    fn flags_bit_vector_port_under_rule() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code("libname", CODE);
+
+        let mut rules = DeprecatedRules::default();
+        rules.enable(DeprecatedRule::BitTypes);
+
+        check_diagnostics(
+            lint(rules, builder),
+            vec![Diagnostic::hint(
+                code.s1("bit_vector"),
+                "array type 'BIT_VECTOR' is deprecated by policy, consider std_logic_vector",
+            )],
+        );
+    }
+
+    #[test]
+    fn flags_process_without_sensitivity_list_under_rule() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code("libname", CODE);
+
+        let mut rules = DeprecatedRules::default();
+        rules.enable(DeprecatedRule::ProcessWithoutSensitivityList);
+
+        check_diagnostics(
+            lint(rules, builder),
+            vec![Diagnostic::hint(
+                code.s1("waiting"),
+                "Process without sensitivity list is deprecated by policy, consider a sensitivity list",
+            )],
+        );
+    }
+
+    #[test]
+    fn nothing_is_flagged_by_default() {
+        let mut builder = LibraryBuilder::new();
+        builder.code("libname", CODE);
+
+        check_no_diagnostics(&lint(DeprecatedRules::default(), builder));
+    }
+}
@@ -0,0 +1,299 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::{find_declarations, CachedLinter};
+use crate::analysis::DesignRoot;
+use crate::analysis::Library;
+use crate::ast::Designator;
+use crate::ast::ObjectClass;
+use crate::ast::UnitId;
+use crate::data::DiagnosticHandler;
+use crate::data::Symbol;
+use crate::named_entity::ObjectInterface;
+use crate::AnyEntKind;
+use crate::Config;
+use crate::Diagnostic;
+use crate::EntRef;
+use crate::Overloaded;
+use fnv::FnvHashMap;
+use std::str::FromStr;
+
+/// The spelling convention an identifier must follow
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IdentifierCase {
+    /// No upper case letters, such as `my_signal`
+    Lower,
+    /// No lower case letters, such as `MY_CONSTANT`
+    Upper,
+    /// Starts with an upper case letter and has no underscores, such as `MyType`
+    Pascal,
+}
+
+impl IdentifierCase {
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            IdentifierCase::Lower => !name.chars().any(|c| c.is_ascii_uppercase()),
+            IdentifierCase::Upper => !name.chars().any(|c| c.is_ascii_lowercase()),
+            IdentifierCase::Pascal => {
+                name.starts_with(|c: char| c.is_ascii_uppercase()) && !name.contains('_')
+            }
+        }
+    }
+
+    pub fn describe(&self) -> &'static str {
+        match self {
+            IdentifierCase::Lower => "lower case",
+            IdentifierCase::Upper => "upper case",
+            IdentifierCase::Pascal => "pascal case",
+        }
+    }
+}
+
+impl FromStr for IdentifierCase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "lower" => Ok(IdentifierCase::Lower),
+            "upper" => Ok(IdentifierCase::Upper),
+            "pascal" => Ok(IdentifierCase::Pascal),
+            _ => Err(format!("'{s}' is not a supported identifier case")),
+        }
+    }
+}
+
+/// The kinds of declarations that a naming rule can be configured for
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NamingKind {
+    Signal,
+    Variable,
+    Constant,
+    Port,
+    Generic,
+    Type,
+    Subprogram,
+}
+
+impl FromStr for NamingKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "signal" => Ok(NamingKind::Signal),
+            "variable" => Ok(NamingKind::Variable),
+            "constant" => Ok(NamingKind::Constant),
+            "port" => Ok(NamingKind::Port),
+            "generic" => Ok(NamingKind::Generic),
+            "type" => Ok(NamingKind::Type),
+            "subprogram" => Ok(NamingKind::Subprogram),
+            _ => Err(format!(
+                "'{s}' is not a kind of declaration with a naming rule"
+            )),
+        }
+    }
+}
+
+impl NamingKind {
+    fn of(ent: EntRef) -> Option<NamingKind> {
+        match ent.kind() {
+            AnyEntKind::Object(obj) => match obj.iface {
+                Some(ObjectInterface::Port(_)) => Some(NamingKind::Port),
+                Some(ObjectInterface::Generic) => Some(NamingKind::Generic),
+                Some(ObjectInterface::Parameter(_)) => None,
+                None => match obj.class {
+                    ObjectClass::Signal => Some(NamingKind::Signal),
+                    ObjectClass::Variable | ObjectClass::SharedVariable => {
+                        Some(NamingKind::Variable)
+                    }
+                    ObjectClass::Constant => Some(NamingKind::Constant),
+                },
+            },
+            AnyEntKind::Type(_) => Some(NamingKind::Type),
+            AnyEntKind::Overloaded(Overloaded::Subprogram(_))
+            | AnyEntKind::Overloaded(Overloaded::SubprogramDecl(_)) => Some(NamingKind::Subprogram),
+            _ => None,
+        }
+    }
+}
+
+/// The identifier case required for each kind of declaration.
+/// Kinds without a rule are not checked.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NamingRules {
+    rules: FnvHashMap<NamingKind, IdentifierCase>,
+}
+
+impl NamingRules {
+    pub fn set(&mut self, kind: NamingKind, case: IdentifierCase) {
+        self.rules.insert(kind, case);
+    }
+
+    pub fn get(&self, kind: NamingKind) -> Option<IdentifierCase> {
+        self.rules.get(&kind).copied()
+    }
+}
+
+/// Find declarations whose spelling does not follow the configured convention
+fn find_case_violations<'a>(
+    root: &'a DesignRoot,
+    lib: &Library,
+    primary_unit_name: &Symbol,
+    rules: &NamingRules,
+) -> Vec<(EntRef<'a>, IdentifierCase)> {
+    find_declarations(root, lib, primary_unit_name)
+        .into_iter()
+        .filter_map(|ent| {
+            let case = rules.get(NamingKind::of(ent)?)?;
+
+            // Extended identifiers are written exactly as intended
+            if let Designator::Identifier(name) = ent.designator() {
+                let name = name.name_utf8();
+                if !name.starts_with('\\') && !case.matches(&name) {
+                    return Some((ent, case));
+                }
+            }
+            None
+        })
+        .collect()
+}
+
+#[derive(Default)]
+pub(crate) struct IdentifierCaseLinter {
+    rules: NamingRules,
+    cache: CachedLinter,
+}
+
+impl IdentifierCaseLinter {
+    pub fn new(rules: NamingRules) -> Self {
+        Self {
+            rules,
+            cache: Default::default(),
+        }
+    }
+
+    pub fn lint(
+        &mut self,
+        root: &DesignRoot,
+        config: &Config,
+        analyzed_units: &[UnitId],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let rules = &self.rules;
+        self.cache.lint(
+            root,
+            config,
+            analyzed_units,
+            diagnostics,
+            |library, name| {
+                find_case_violations(root, library, name, rules)
+                    .into_iter()
+                    .filter_map(|(ent, case)| {
+                        Some(Diagnostic::warning(
+                            ent.decl_pos()?,
+                            format!(
+                                "Spelling of {} does not follow the {} convention",
+                                ent.describe(),
+                                case.describe()
+                            ),
+                        ))
+                    })
+                    .collect()
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::tests::LibraryBuilder;
+    use crate::lint::test::lint_entity;
+    use crate::syntax::test::{check_diagnostics, check_no_diagnostics};
+
+    #[test]
+    fn identifier_case_matches() {
+        assert!(IdentifierCase::Lower.matches("my_signal"));
+        assert!(!IdentifierCase::Lower.matches("My_signal"));
+        assert!(IdentifierCase::Upper.matches("MY_CONSTANT"));
+        assert!(!IdentifierCase::Upper.matches("MY_Constant"));
+        assert!(IdentifierCase::Pascal.matches("MyType"));
+        assert!(!IdentifierCase::Pascal.matches("my_type"));
+        assert!(!IdentifierCase::Pascal.matches("My_Type"));
+    }
+
+    #[test]
+    fn upper_case_signal_violates_lower_case_convention() {
+        let mut builder = LibraryBuilder::new();
+
+        let code = builder.code(
+            "libname",
+            "
+entity ent is
+  port (CLK : in bit);
+end entity;
+
+architecture a of ent is
+  signal BAD_SIG : boolean;
+  signal good_sig : boolean;
+  type MyType is (alpha, beta);
+begin
+end architecture;",
+        );
+
+        let (root, diagnostics) = builder.get_analyzed_root();
+        check_no_diagnostics(&diagnostics);
+
+        let mut rules = NamingRules::default();
+        rules.set(NamingKind::Signal, IdentifierCase::Lower);
+        rules.set(NamingKind::Type, IdentifierCase::Pascal);
+
+        let lib = root.get_lib(&root.symbol_utf8("libname")).unwrap();
+        let violations: Vec<_> = find_case_violations(&root, lib, &root.symbol_utf8("ent"), &rules)
+            .into_iter()
+            .map(|(ent, case)| (ent.decl_pos().cloned(), case))
+            .collect();
+
+        // Ports have no configured rule and are not checked
+        assert_eq!(
+            violations,
+            vec![(Some(code.s1("BAD_SIG").pos()), IdentifierCase::Lower)]
+        );
+    }
+
+    #[test]
+    fn warns_at_declaration_of_violating_identifier() {
+        let mut builder = LibraryBuilder::new();
+
+        let code = builder.code(
+            "libname",
+            "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal BAD_SIG : boolean;
+  signal good_sig : boolean;
+begin
+end architecture;",
+        );
+
+        let mut rules = NamingRules::default();
+        rules.set(NamingKind::Signal, IdentifierCase::Lower);
+        let mut linter = IdentifierCaseLinter::new(rules);
+
+        let diagnostics = lint_entity(builder, |root, config, units, diagnostics| {
+            linter.lint(root, config, units, diagnostics)
+        });
+
+        check_diagnostics(
+            diagnostics,
+            vec![Diagnostic::warning(
+                code.s1("BAD_SIG"),
+                "Spelling of signal 'BAD_SIG' does not follow the lower case convention",
+            )],
+        );
+    }
+}
@@ -29,6 +29,10 @@ struct Args {
     #[arg(long, default_value_t = false)]
     no_hint: bool,
 
+    /// Only report syntax errors, skipping semantic analysis
+    #[arg(long, default_value_t = false)]
+    syntax_only: bool,
+
     /// Config file in TOML format containing libraries and settings
     #[arg(short, long)]
     config: String,
@@ -66,15 +70,22 @@ fn main() {
         println!("Running {iterations} iterations for benchmarking");
         for _ in 0..(iterations - 1) {
             let mut project = Project::from_config(config.clone(), &mut NullMessages);
-            project.analyse();
+            analyse(&mut project, args.syntax_only);
         }
         iterations
     } else {
         1
     };
 
-    let mut project = Project::from_config(config, &mut msg_printer);
-    let mut diagnostics = project.analyse();
+    let mut project = if args.perf {
+        let mut project = Project::new();
+        project.enable_timing();
+        project.update_config(config, &mut msg_printer);
+        project
+    } else {
+        Project::from_config(config, &mut msg_printer)
+    };
+    let mut diagnostics = analyse(&mut project, args.syntax_only);
     let duration = start.elapsed().unwrap() / iterations;
 
     if args.no_hint {
@@ -98,6 +109,12 @@ fn main() {
             duration.as_millis(),
             duration_per_line.as_nanos()
         );
+
+        if let Some(report) = project.timing_report() {
+            for (phase, elapsed) in report.iter() {
+                println!("{phase:?} took {} ms", elapsed.as_millis());
+            }
+        }
     }
 
     if args.dump_unresolved || args.count_unresolved {
@@ -118,6 +135,14 @@ fn main() {
     std::process::exit(0);
 }
 
+fn analyse(project: &mut Project, syntax_only: bool) -> Vec<Diagnostic> {
+    if syntax_only {
+        project.analyse_syntax()
+    } else {
+        project.analyse()
+    }
+}
+
 fn show_diagnostics(diagnostics: &[Diagnostic]) {
     for diagnostic in diagnostics {
         println!("{}", diagnostic.show());
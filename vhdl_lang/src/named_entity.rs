@@ -15,7 +15,7 @@ use crate::ast::{ExternalObjectClass, InterfaceDeclaration, InterfaceObjectDecla
 use crate::data::*;
 mod types;
 use fnv::FnvHashMap;
-pub use types::{BaseType, Subtype, Type, TypeEnt, TypedSelection, UniversalType};
+pub use types::{BaseType, StaticRange, Subtype, Type, TypeEnt, TypedSelection, UniversalType};
 mod overloaded;
 pub use overloaded::{Overloaded, OverloadedEnt, Signature, SignatureKey, SubprogramKey};
 mod object;
@@ -611,6 +611,7 @@ impl HasEntityId for Declaration {
             Declaration::Package(pkg) => pkg.ent_id(),
             Declaration::Use(_) => None,
             Declaration::Configuration(_) => None,
+            Declaration::Disconnection(_) => None,
         }
     }
 }
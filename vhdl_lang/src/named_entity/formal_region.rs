@@ -86,6 +86,13 @@ impl<'a> InterfaceEnt<'a> {
         }
     }
 
+    pub fn mode(&self) -> Option<Mode> {
+        match self.ent.kind() {
+            AnyEntKind::Object(obj) => obj.mode(),
+            _ => None,
+        }
+    }
+
     pub fn type_mark(&self) -> TypeEnt<'a> {
         match self.ent.kind() {
             AnyEntKind::Object(obj) => obj.subtype.type_mark(),
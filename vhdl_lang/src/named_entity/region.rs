@@ -390,6 +390,18 @@ impl<'a> NamedEntities<'a> {
     pub fn first_kind(&self) -> &'a AnyEntKind<'a> {
         self.first().kind()
     }
+
+    /// All entities sorted by declaration position
+    pub fn all_entities(&self) -> Vec<EntRef<'a>> {
+        match self {
+            Self::Single(ent) => vec![ent],
+            Self::Overloaded(overloaded) => overloaded
+                .sorted_entities()
+                .into_iter()
+                .map(|ent| ent.into())
+                .collect(),
+        }
+    }
 }
 
 #[derive(Copy, Clone, PartialEq, Default)]
@@ -7,7 +7,7 @@
 use std::ops::Deref;
 
 use super::*;
-use crate::ast::{Designator, HasDesignator, Ident, WithDecl, WithRef};
+use crate::ast::{Designator, Direction, HasDesignator, Ident, WithDecl, WithRef};
 use crate::data::WithPos;
 use crate::{Diagnostic, SrcPos};
 
@@ -22,6 +22,10 @@ pub enum Type<'a> {
         // Indexes are Option<> to handle unknown types
         indexes: Vec<Option<BaseType<'a>>>,
         elem_type: TypeEnt<'a>,
+        // Constrained array definitions such as array (0 to 7) of bit
+        is_constrained: bool,
+        // The index constraint of a constrained one-dimensional array definition when statically known
+        constraint: Option<StaticRange>,
     },
     Enum(FnvHashSet<Designator>),
     Integer,
@@ -178,6 +182,26 @@ impl<'a> TypeEnt<'a> {
         self.base().sliced_as()
     }
 
+    /// An array type or subtype without an index constraint such as `bit_vector`
+    pub fn is_unconstrained_array(&self) -> bool {
+        match self.kind() {
+            Type::Array { is_constrained, .. } => !is_constrained,
+            Type::Subtype(subtype) => subtype.is_unconstrained_array(),
+            Type::Alias(typ) => typ.is_unconstrained_array(),
+            _ => false,
+        }
+    }
+
+    /// The index range of a constrained one-dimensional array type or subtype when statically known
+    pub fn index_range(&self) -> Option<StaticRange> {
+        match self.kind() {
+            Type::Array { constraint, .. } => *constraint,
+            Type::Subtype(subtype) => subtype.index_range(),
+            Type::Alias(typ) => typ.index_range(),
+            _ => None,
+        }
+    }
+
     /// Lookup a selected name prefix.suffix
     /// where prefix has this type
     pub fn selected(
@@ -402,11 +426,13 @@ impl<'a> BaseType<'a> {
         if let Type::Array {
             indexes: my_indexes,
             elem_type: my_elem_type,
+            ..
         } = self.kind()
         {
             if let Type::Array {
                 indexes: other_indexes,
                 elem_type: other_elem_type,
+                ..
             } = other.kind()
             {
                 return my_indexes.len() == other_indexes.len()
@@ -439,20 +465,81 @@ impl<'a> Deref for BaseType<'a> {
     }
 }
 
+/// A range with bounds that are known during analysis such as `7 downto 0`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StaticRange {
+    pub left: i64,
+    pub direction: Direction,
+    pub right: i64,
+}
+
+impl StaticRange {
+    /// The lowest and highest value of the range, the lowest is greater for a null range
+    pub fn bounds(&self) -> (i64, i64) {
+        match self.direction {
+            Direction::Ascending => (self.left, self.right),
+            Direction::Descending => (self.right, self.left),
+        }
+    }
+
+    /// The number of values within the range, zero for a null range
+    pub fn length(&self) -> u64 {
+        let (low, high) = self.bounds();
+        if high < low {
+            0
+        } else {
+            high.abs_diff(low) + 1
+        }
+    }
+}
+
+impl std::fmt::Display for StaticRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.left, self.direction, self.right)
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct Subtype<'a> {
     pub(crate) type_mark: TypeEnt<'a>,
+    // The range constraint of a scalar subtype or the index constraint
+    // of a one-dimensional array subtype when statically known
+    pub(crate) constraint: Option<StaticRange>,
+    // The subtype indication has an index constraint that is not necessarily static
+    pub(crate) has_index_constraint: bool,
 }
 
 impl<'a> Subtype<'a> {
     pub fn new(type_mark: TypeEnt<'a>) -> Subtype<'a> {
-        Subtype { type_mark }
+        Subtype {
+            type_mark,
+            constraint: None,
+            has_index_constraint: false,
+        }
+    }
+
+    pub fn with_constraint(type_mark: TypeEnt<'a>, constraint: Option<StaticRange>) -> Subtype<'a> {
+        Subtype {
+            type_mark,
+            constraint,
+            has_index_constraint: false,
+        }
     }
 
     pub fn type_mark(&self) -> TypeEnt<'a> {
         self.type_mark
     }
 
+    pub fn constraint(&self) -> Option<StaticRange> {
+        self.constraint
+    }
+
+    /// The number of elements of a constrained one-dimensional array subtype
+    /// or the number of values of a constrained scalar subtype
+    pub fn length(&self) -> Option<u64> {
+        self.constraint.map(|range| range.length())
+    }
+
     pub fn base_type(&self) -> TypeEnt<'a> {
         self.type_mark.base_type()
     }
@@ -460,6 +547,19 @@ impl<'a> Subtype<'a> {
     pub fn base(&self) -> BaseType<'a> {
         self.type_mark.base()
     }
+
+    pub fn is_unconstrained_array(&self) -> bool {
+        !self.has_index_constraint
+            && self.constraint.is_none()
+            && self.type_mark.is_unconstrained_array()
+    }
+
+    pub fn index_range(&self) -> Option<StaticRange> {
+        if !matches!(self.base_type().kind(), Type::Array { .. }) {
+            return None;
+        }
+        self.constraint.or_else(|| self.type_mark.index_range())
+    }
 }
 
 /// The result of selecting an object
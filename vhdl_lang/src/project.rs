@@ -4,20 +4,58 @@
 //
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 
-use crate::analysis::DesignRoot;
+use crate::analysis::{DesignRoot, ResolvedName};
 use crate::ast::search::Searcher;
 use crate::ast::DesignFile;
 use crate::completion::{list_completion_options, CompletionItem};
 use crate::config::Config;
+use crate::lint::buffer_port::BufferPortLinter;
 use crate::lint::dead_code::UnusedDeclarationsLinter;
+use crate::lint::deprecated::{DeprecatedConstructLinter, DeprecatedRules};
+use crate::lint::naming::{IdentifierCaseLinter, NamingRules};
 use crate::named_entity::{AnyEnt, EntRef};
+use crate::standard::check_standard;
 use crate::syntax::VHDLParser;
-use crate::{data::*, EntHierarchy, EntityId};
+use crate::{data::*, EntHierarchy, EntityId, SymbolUnderCursor};
 use fnv::{FnvHashMap, FnvHashSet};
 use std::collections::hash_map::Entry;
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 use vhdl_lang::Token;
 
+/// A phase of the analysis whose elapsed time can be recorded.
+/// Lexing is done on demand by the parser and is part of the parse phase,
+/// name resolution and type checking are done together in the analysis phase.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    Analysis,
+    Lint,
+}
+
+/// The elapsed time of each phase accumulated since timing was enabled
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TimingReport {
+    elapsed: BTreeMap<Phase, Duration>,
+}
+
+impl TimingReport {
+    pub fn get(&self, phase: Phase) -> Option<Duration> {
+        self.elapsed.get(&phase).copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Phase, Duration)> + '_ {
+        self.elapsed
+            .iter()
+            .map(|(phase, elapsed)| (*phase, *elapsed))
+    }
+
+    fn record(&mut self, phase: Phase, elapsed: Duration) {
+        *self.elapsed.entry(phase).or_default() += elapsed;
+    }
+}
+
 pub struct Project {
     parser: VHDLParser,
     config: Config,
@@ -25,6 +63,10 @@ pub struct Project {
     files: FnvHashMap<FilePath, SourceFile>,
     empty_libraries: FnvHashSet<Symbol>,
     lint: Option<UnusedDeclarationsLinter>,
+    naming_lint: Option<IdentifierCaseLinter>,
+    buffer_port_lint: Option<BufferPortLinter>,
+    deprecated_lint: Option<DeprecatedConstructLinter>,
+    timing: Option<TimingReport>,
 }
 
 impl Project {
@@ -36,6 +78,10 @@ impl Project {
             empty_libraries: FnvHashSet::default(),
             parser,
             lint: None,
+            naming_lint: None,
+            buffer_port_lint: None,
+            deprecated_lint: None,
+            timing: None,
             config: Config::default(),
         }
     }
@@ -44,6 +90,51 @@ impl Project {
         self.lint = Some(UnusedDeclarationsLinter::default());
     }
 
+    /// Warn about declarations whose spelling does not follow the given rules
+    pub fn enable_identifier_case_rules(&mut self, rules: NamingRules) {
+        self.naming_lint = Some(IdentifierCaseLinter::new(rules));
+    }
+
+    /// Warn about ports of mode buffer
+    pub fn enable_buffer_port_detection(&mut self) {
+        self.buffer_port_lint = Some(BufferPortLinter::default());
+    }
+
+    /// Hint about legacy constructs that are discouraged by the enabled rules
+    pub fn enable_deprecated_construct_rules(&mut self, rules: DeprecatedRules) {
+        self.deprecated_lint = Some(DeprecatedConstructLinter::new(rules));
+    }
+
+    /// Record the elapsed time of each phase from now on.
+    /// Files that are parsed by `from_config` are parsed before timing can be enabled,
+    /// use `update_config` on a new project to include them.
+    pub fn enable_timing(&mut self) {
+        self.timing = Some(TimingReport::default());
+    }
+
+    /// The elapsed time of each phase if timing is enabled
+    pub fn timing_report(&self) -> Option<&TimingReport> {
+        self.timing.as_ref()
+    }
+
+    fn start_timer(&self) -> Option<Instant> {
+        self.timing.as_ref().map(|_| Instant::now())
+    }
+
+    fn stop_timer(&mut self, phase: Phase, start: Option<Instant>) {
+        if let (Some(timing), Some(start)) = (self.timing.as_mut(), start) {
+            timing.record(phase, start.elapsed());
+        }
+    }
+
+    /// Make the declarations of a package implicitly visible in all design units,
+    /// for example to accept operator overloads that a vendor tool predefines
+    pub fn add_predefined_package(&mut self, library_name: &str, package_name: &str) {
+        let library_name = self.root.symbol_utf8(library_name);
+        let package_name = self.root.symbol_utf8(package_name);
+        self.root.add_predefined_package(library_name, package_name);
+    }
+
     /// Create instance from given configuration.
     /// Files referred by configuration are parsed into corresponding libraries.
     pub fn from_config(config: Config, messages: &mut dyn MessageHandler) -> Project {
@@ -54,10 +145,75 @@ impl Project {
         project
     }
 
+    /// Analyze all VHDL files within a directory that match a glob pattern together
+    /// with the libraries of the configuration. Files are added to the library given
+    /// by `library_mapping`, keyed by the file name relative to the directory,
+    /// and otherwise to `default_library`.
+    /// The diagnostics are returned grouped by file name.
+    pub fn analyse_directory(
+        config: Config,
+        directory: &Path,
+        pattern: &str,
+        default_library: &str,
+        library_mapping: &FnvHashMap<PathBuf, String>,
+        messages: &mut dyn MessageHandler,
+    ) -> BTreeMap<PathBuf, Vec<Diagnostic>> {
+        let mut project = Project::from_config(config, messages);
+        let mut files: FnvHashMap<FilePath, FnvHashSet<Symbol>> = FnvHashMap::default();
+
+        let full_pattern = directory.join(pattern);
+        match glob::glob(&full_pattern.to_string_lossy()) {
+            Ok(paths) => {
+                for file_path_or_error in paths {
+                    let file_path = match file_path_or_error {
+                        Ok(file_path) => file_path,
+                        Err(err) => {
+                            messages.push(Message::error(err.to_string()));
+                            continue;
+                        }
+                    };
+
+                    if !is_vhdl_file(&file_path) {
+                        continue;
+                    }
+
+                    let relative_path = file_path.strip_prefix(directory).unwrap_or(&file_path);
+                    let library_name = library_mapping
+                        .get(relative_path)
+                        .map(String::as_str)
+                        .unwrap_or(default_library);
+
+                    files
+                        .entry(FilePath::new(&file_path))
+                        .or_default()
+                        .insert(project.root.symbol_utf8(library_name));
+                }
+            }
+            Err(err) => {
+                messages.push(Message::error(format!(
+                    "Invalid pattern '{}' {err}",
+                    full_pattern.display()
+                )));
+            }
+        }
+
+        project.parse_and_add_files(files, messages);
+
+        let mut grouped: BTreeMap<PathBuf, Vec<Diagnostic>> = BTreeMap::new();
+        for diagnostic in project.analyse() {
+            grouped
+                .entry(diagnostic.pos.source.file_name().to_owned())
+                .or_default()
+                .push(diagnostic);
+        }
+        grouped
+    }
+
     /// Replace active project configuration.
     /// The design state is reset, new files are added and parsed. Existing source files will be
     /// kept and parsed from in-memory source (required for incremental document updates).
     pub fn update_config(&mut self, config: Config, messages: &mut dyn MessageHandler) {
+        let start = self.start_timer();
         self.parser = VHDLParser::default();
         self.root = DesignRoot::new(self.parser.symbols.clone());
 
@@ -86,6 +242,7 @@ impl Project {
 
         self.config = config;
         self.parse_and_add_files(new_files, messages);
+        self.stop_timer(Phase::Parse, start);
     }
 
     fn load_files_from_config(
@@ -207,19 +364,60 @@ impl Project {
                 }
             }
         };
+        let start = self.start_timer();
         source_file.parser_diagnostics.clear();
         source_file.design_file = self
             .parser
             .parse_design_source(source, &mut source_file.parser_diagnostics);
+        self.stop_timer(Phase::Parse, start);
         self.files
             .insert(source.file_path().to_owned(), source_file);
     }
 
+    /// Add a source to a library without reading it from the file system,
+    /// for example to provide the standard libraries from memory.
+    /// The library mapping is not part of the configuration and is lost on `update_config`.
+    pub fn add_source_to_library(&mut self, library_name: &str, source: &Source) {
+        let library_name = self.root.symbol_utf8(library_name);
+
+        if let Some(source_file) = self.files.get_mut(source.file_path()) {
+            source_file.library_names.insert(library_name);
+        } else {
+            let mut library_names = FnvHashSet::default();
+            library_names.insert(library_name);
+
+            self.files.insert(
+                source.file_path().to_owned(),
+                SourceFile {
+                    source: source.clone(),
+                    library_names,
+                    parser_diagnostics: vec![],
+                    design_file: DesignFile::default(),
+                },
+            );
+        }
+
+        self.update_source(source);
+    }
+
     pub fn analyse(&mut self) -> Vec<Diagnostic> {
+        let start = self.start_timer();
         let mut diagnostics = Vec::new();
 
         for source_file in self.files.values_mut() {
             let design_file = source_file.take_design_file();
+
+            // Only freshly parsed files carry a design file, the diagnostics of
+            // earlier analyses are kept together with the parser diagnostics
+            if let Some(standard) = self.config.standard() {
+                if !design_file.design_units.is_empty()
+                    && source_file.is_checked_against_standard(&self.config)
+                {
+                    let errors = check_standard(&design_file, standard);
+                    source_file.parser_diagnostics.extend(errors);
+                }
+            }
+
             // Avoid cloning design files for single library
             let mut design_files = multiply(design_file, source_file.library_names.len());
 
@@ -238,14 +436,39 @@ impl Project {
         }
 
         let analyzed_units = self.root.analyze(&mut diagnostics);
+        self.stop_timer(Phase::Analysis, start);
 
+        let start = self.start_timer();
         if let Some(ref mut lint) = self.lint {
             lint.lint(&self.root, &self.config, &analyzed_units, &mut diagnostics);
         }
 
+        if let Some(ref mut lint) = self.naming_lint {
+            lint.lint(&self.root, &self.config, &analyzed_units, &mut diagnostics);
+        }
+
+        if let Some(ref mut lint) = self.buffer_port_lint {
+            lint.lint(&self.root, &self.config, &analyzed_units, &mut diagnostics);
+        }
+
+        if let Some(ref mut lint) = self.deprecated_lint {
+            lint.lint(&self.root, &self.config, &analyzed_units, &mut diagnostics);
+        }
+        self.stop_timer(Phase::Lint, start);
+
         diagnostics
     }
 
+    /// Only report diagnostics from lexing and parsing.
+    /// Semantic analysis is skipped entirely which is cheaper than `analyse`
+    /// when only syntax errors are of interest.
+    pub fn analyse_syntax(&self) -> Vec<Diagnostic> {
+        self.files
+            .values()
+            .flat_map(|source_file| source_file.parser_diagnostics.iter().cloned())
+            .collect()
+    }
+
     /// Search for reference at position
     /// Character offset on a line in a document (zero-based). Assuming that the line is
     /// represented as a string, the `character` value represents the gap between the
@@ -308,6 +531,39 @@ impl Project {
         self.root.find_all_references(ent)
     }
 
+    /// Search for all references to the item at the cursor within the same source
+    pub fn highlights_at(&self, source: &Source, cursor: Position) -> Vec<SrcPos> {
+        self.root.highlights_at(source, cursor)
+    }
+
+    /// Find the innermost declarative region that contains the cursor
+    pub fn region_at(&self, source: &Source, cursor: Position) -> Option<EntRef<'_>> {
+        self.root.region_at(source, cursor)
+    }
+
+    /// Resolve a name such as `work.pkg.func` as if it was written within the region
+    pub fn resolve_in_region<'a>(
+        &'a self,
+        region: EntRef<'a>,
+        name: &str,
+    ) -> DiagnosticResult<ResolvedName<'a>> {
+        self.root.resolve_in_region(region, name)
+    }
+
+    /// Every entity that a simple name could refer to within the region, such as all overloads
+    pub fn resolve_all_in_region<'a>(
+        &'a self,
+        region: EntRef<'a>,
+        name: &str,
+    ) -> DiagnosticResult<Vec<EntRef<'a>>> {
+        self.root.resolve_all_in_region(region, name)
+    }
+
+    /// Classify the token at the cursor as a declared name, keyword or literal
+    pub fn symbol_under_cursor(&self, source: &Source, cursor: Position) -> SymbolUnderCursor {
+        self.root.symbol_under_cursor(source, cursor)
+    }
+
     /// Get source positions that are not resolved to a declaration
     /// This is used for development to test where the language server is blind
     pub fn find_all_unresolved(&self) -> (usize, Vec<SrcPos>) {
@@ -362,11 +618,27 @@ impl SourceFile {
         std::mem::take(&mut self.design_file)
     }
 
+    /// Files of third party libraries are not required to follow the configured standard
+    fn is_checked_against_standard(&self, config: &Config) -> bool {
+        self.library_names.iter().any(|library_name| {
+            config
+                .get_library(&library_name.name_utf8())
+                .is_none_or(|library| !library.is_third_party)
+        })
+    }
+
     pub fn num_lines(&self) -> usize {
         self.source.contents().num_lines()
     }
 }
 
+fn is_vhdl_file(file_name: &Path) -> bool {
+    file_name
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("vhd") || ext.eq_ignore_ascii_case("vhdl"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -403,6 +675,269 @@ lib.files = ['file.vhd']
         check_no_diagnostics(&project.analyse());
     }
 
+    #[test]
+    fn analyse_directory_groups_diagnostics_by_file() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(
+            root.path().join("ent.vhd"),
+            "
+library lib2;
+use lib2.pkg;
+
+entity ent is
+end entity;
+",
+        )
+        .unwrap();
+        std::fs::write(
+            root.path().join("pkg.vhdl"),
+            "
+library lib1;
+use lib1.missing;
+
+package pkg is
+end package;
+",
+        )
+        .unwrap();
+        std::fs::write(root.path().join("notes.txt"), "not vhdl").unwrap();
+
+        let mut library_mapping = FnvHashMap::default();
+        library_mapping.insert(PathBuf::from("pkg.vhdl"), "lib2".to_owned());
+
+        let mut messages = Vec::new();
+        let grouped = Project::analyse_directory(
+            Config::default(),
+            root.path(),
+            "*",
+            "lib1",
+            &library_mapping,
+            &mut messages,
+        );
+        assert_eq!(messages, vec![]);
+
+        let file_names: Vec<_> = grouped.keys().cloned().collect();
+        assert_eq!(file_names, vec![root.path().join("pkg.vhdl")]);
+
+        let diagnostics = &grouped[&root.path().join("pkg.vhdl")];
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "No primary unit 'missing' within library 'lib1'"
+        );
+    }
+
+    /// An unsaved editor buffer replaces the file on disk but keeps its file name
+    #[test]
+    fn in_memory_source_replaces_file_contents() {
+        let root = tempfile::tempdir().unwrap();
+        let file_name = root.path().join("ent.vhd");
+        std::fs::write(
+            &file_name,
+            "
+entity ent is
+end entity;
+",
+        )
+        .unwrap();
+        let config = Config::from_str("[libraries]\nlib.files = ['ent.vhd']", root.path()).unwrap();
+
+        let mut messages = Vec::new();
+        let mut project = Project::from_config(config, &mut messages);
+        assert_eq!(messages, vec![]);
+        check_no_diagnostics(&project.analyse());
+
+        let unsaved = "
+use work.missing;
+entity ent is
+end entity;
+";
+        let source = project.get_source(&file_name).unwrap();
+        project.update_source(&Source::inline(source.file_name(), unsaved));
+
+        let diagnostics = project.analyse();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "No primary unit 'missing' within library 'lib'"
+        );
+        assert_eq!(diagnostics[0].pos.source.file_name(), file_name.as_path());
+        assert_eq!(
+            project
+                .get_source(&file_name)
+                .unwrap()
+                .contents()
+                .get_line(1),
+            Some("use work.missing;\n")
+        );
+        assert_eq!(
+            std::fs::read_to_string(&file_name).unwrap().lines().nth(1),
+            Some("entity ent is")
+        );
+    }
+
+    #[test]
+    fn timing_report_is_populated_when_enabled() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(
+            root.path().join("ent.vhd"),
+            "
+entity ent is
+end entity;
+",
+        )
+        .unwrap();
+        let config = Config::from_str("[libraries]\nlib.files = ['ent.vhd']", root.path()).unwrap();
+
+        let mut messages = Vec::new();
+        let mut project = Project::from_config(config.clone(), &mut messages);
+        project.analyse();
+        assert_eq!(project.timing_report(), None);
+
+        let mut project = Project::new();
+        project.enable_timing();
+        project.update_config(config, &mut messages);
+        project.enable_unused_declaration_detection();
+        check_no_diagnostics(&project.analyse());
+        assert_eq!(messages, vec![]);
+
+        let phases: Vec<_> = project
+            .timing_report()
+            .unwrap()
+            .iter()
+            .map(|(phase, _)| phase)
+            .collect();
+        assert_eq!(phases, vec![Phase::Parse, Phase::Analysis, Phase::Lint]);
+    }
+
+    #[test]
+    fn reports_constructs_newer_than_configured_standard() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(
+            root.path().join("ent.vhd"),
+            "
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+begin
+    process
+        variable v : std_ulogic;
+    begin
+        case? v is
+            when '1' => null;
+            when others => null;
+        end case?;
+        wait;
+    end process;
+end architecture;
+",
+        )
+        .unwrap();
+
+        // The standard libraries use VHDL-2008 constructs but are third party
+        let libraries = Path::new(env!("CARGO_MANIFEST_DIR")).join("../vhdl_libraries");
+        let config = Config::from_str(
+            &format!(
+                "
+standard = '1993'
+
+[libraries]
+std.files = ['{0}/std/*.vhd']
+std.is_third_party = true
+ieee.files = ['{0}/ieee2008/std_logic_1164*.vhdl']
+ieee.is_third_party = true
+lib.files = ['ent.vhd']
+",
+                libraries.display()
+            ),
+            root.path(),
+        )
+        .unwrap();
+
+        let mut messages = Vec::new();
+        let mut project = Project::from_config(config, &mut messages);
+        assert_eq!(messages, vec![]);
+
+        let diagnostics = project.analyse();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "'case?' requires VHDL-2008 but the configured standard is VHDL-1993"
+        );
+        let range = diagnostics[0].pos.range();
+        assert_eq!((range.start.line, range.start.character), (12, 8));
+        assert_eq!((range.end.line, range.end.character), (12, 15));
+
+        // The error remains after an analysis without re-parsing
+        assert_eq!(project.analyse().len(), 1);
+    }
+
+    fn latin1_source(file_name: &str, bytes: &[u8]) -> Source {
+        Source::inline(Path::new(file_name), &Latin1String::new(bytes).to_string())
+    }
+
+    #[test]
+    fn standard_libraries_from_memory() {
+        let mut project = Project::new();
+
+        for (file_name, bytes) in [
+            (
+                "standard.vhd",
+                &include_bytes!("../../vhdl_libraries/std/standard.vhd")[..],
+            ),
+            (
+                "textio.vhd",
+                &include_bytes!("../../vhdl_libraries/std/textio.vhd")[..],
+            ),
+            (
+                "env.vhd",
+                &include_bytes!("../../vhdl_libraries/std/env.vhd")[..],
+            ),
+        ] {
+            project.add_source_to_library("std", &latin1_source(file_name, bytes));
+        }
+
+        for (file_name, bytes) in [
+            (
+                "std_logic_1164.vhdl",
+                &include_bytes!("../../vhdl_libraries/ieee2008/std_logic_1164.vhdl")[..],
+            ),
+            (
+                "std_logic_1164-body.vhdl",
+                &include_bytes!("../../vhdl_libraries/ieee2008/std_logic_1164-body.vhdl")[..],
+            ),
+        ] {
+            project.add_source_to_library("ieee", &latin1_source(file_name, bytes));
+        }
+
+        project.add_source_to_library(
+            "lib",
+            &Source::inline(
+                Path::new("ent.vhd"),
+                "
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity ent is
+  port (clk : in std_logic);
+end entity;
+
+architecture a of ent is
+  signal q : std_logic_vector(1 downto 0);
+begin
+  q <= (others => '0') when rising_edge(clk);
+end architecture;
+",
+            ),
+        );
+
+        check_no_diagnostics(&project.analyse());
+    }
+
     #[test]
     fn unmapped_libraries_are_analyzed() {
         let mut messages = Vec::new();
@@ -438,6 +973,38 @@ end architecture;
         assert_eq!(diag.message, "Duplicate architecture 'rtl' of entity 'ent'")
     }
 
+    #[test]
+    fn syntax_only_analysis_skips_semantic_errors() {
+        let mut messages = Vec::new();
+        let mut project = Project::from_config(Config::default(), &mut messages);
+        assert_eq!(messages, vec![]);
+
+        let source = Source::inline(
+            Path::new("file.vhd"),
+            "
+entity ent is
+end entity;
+
+architecture rtl of ent is
+  signal sig : missing_t;
+begin
+  sig <= ;
+end architecture;
+",
+        );
+        project.update_source(&source);
+
+        let diagnostics = project.analyse_syntax();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "Expected {expression}");
+
+        let diagnostics = project.analyse();
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics
+            .iter()
+            .any(|diag| diag.message == "No declaration of 'missing_t'"));
+    }
+
     /// Test that the same file can be added to several libraries
     #[test]
     fn test_same_file_in_multiple_libraries() {
@@ -654,4 +1221,181 @@ lib.files = ['file2.vhd']
         assert_eq!(diagnostics[0].pos.source, source2); // No such library
         assert_eq!(diagnostics[1].pos.source, source2); // No declaration
     }
+
+    #[test]
+    fn identifier_case_rules_from_config() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(
+            root.path().join("ent.vhd"),
+            "
+entity ent is
+end entity;
+
+architecture a of ent is
+    signal BAD_SIG : boolean;
+    signal good_sig : boolean;
+begin
+end architecture;
+",
+        )
+        .unwrap();
+
+        let libraries = Path::new(env!("CARGO_MANIFEST_DIR")).join("../vhdl_libraries");
+        let config = Config::from_str(
+            &format!(
+                "
+[lint]
+identifier_case.signal = 'lower'
+
+[libraries]
+std.files = ['{0}/std/*.vhd']
+std.is_third_party = true
+lib.files = ['ent.vhd']
+",
+                libraries.display()
+            ),
+            root.path(),
+        )
+        .unwrap();
+
+        let mut messages = Vec::new();
+        let mut project = Project::from_config(config.clone(), &mut messages);
+        assert_eq!(messages, vec![]);
+        project.enable_identifier_case_rules(config.naming_rules().unwrap().clone());
+
+        let diagnostics = project.analyse();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "Spelling of signal 'BAD_SIG' does not follow the lower case convention"
+        );
+
+        assert_eq!(
+            Config::from_str(
+                "
+[lint]
+identifier_case.signal = 'camel'
+
+[libraries]
+",
+                root.path()
+            ),
+            Err("'camel' is not a supported identifier case".to_owned())
+        );
+    }
+
+    #[test]
+    fn buffer_port_detection_from_config() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(
+            root.path().join("ent.vhd"),
+            "
+entity ent is
+    port (
+        q : buffer bit;
+        d : in bit
+    );
+end entity;
+",
+        )
+        .unwrap();
+
+        let libraries = Path::new(env!("CARGO_MANIFEST_DIR")).join("../vhdl_libraries");
+        let config = Config::from_str(
+            &format!(
+                "
+[lint]
+buffer_ports = true
+
+[libraries]
+std.files = ['{0}/std/*.vhd']
+std.is_third_party = true
+lib.files = ['ent.vhd']
+",
+                libraries.display()
+            ),
+            root.path(),
+        )
+        .unwrap();
+        assert!(config.buffer_port_detection());
+
+        let mut messages = Vec::new();
+        let mut project = Project::from_config(config, &mut messages);
+        assert_eq!(messages, vec![]);
+        project.enable_buffer_port_detection();
+
+        let diagnostics = project.analyse();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "Port 'q' has mode buffer, consider mode out together with an internal signal"
+        );
+
+        assert_eq!(
+            Config::from_str("[lint]\nbuffer_ports = 'yes'\n\n[libraries]", root.path()),
+            Err("lint.buffer_ports must be a boolean".to_owned())
+        );
+        assert!(!Config::from_str("[libraries]", root.path())
+            .unwrap()
+            .buffer_port_detection());
+    }
+
+    #[test]
+    fn deprecated_construct_rules_from_config() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(
+            root.path().join("ent.vhd"),
+            "
+entity ent is
+end entity;
+
+architecture a of ent is
+begin
+    main : process
+    begin
+        wait;
+    end process;
+end architecture;
+",
+        )
+        .unwrap();
+
+        let libraries = Path::new(env!("CARGO_MANIFEST_DIR")).join("../vhdl_libraries");
+        let config = Config::from_str(
+            &format!(
+                "
+[lint]
+deprecated = ['process_without_sensitivity_list']
+
+[libraries]
+std.files = ['{0}/std/*.vhd']
+std.is_third_party = true
+lib.files = ['ent.vhd']
+",
+                libraries.display()
+            ),
+            root.path(),
+        )
+        .unwrap();
+
+        let mut messages = Vec::new();
+        let mut project = Project::from_config(config.clone(), &mut messages);
+        assert_eq!(messages, vec![]);
+        project.enable_deprecated_construct_rules(config.deprecated_rules().unwrap().clone());
+
+        let diagnostics = project.analyse();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "Process without sensitivity list is deprecated by policy, consider a sensitivity list"
+        );
+
+        assert_eq!(
+            Config::from_str(
+                "[lint]\ndeprecated = ['std_logic']\n\n[libraries]",
+                root.path()
+            ),
+            Err("'std_logic' is not a supported deprecation rule".to_owned())
+        );
+    }
 }
@@ -0,0 +1,251 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+//! Detection of the VHDL standard required by a design file
+
+use crate::ast::search::*;
+use crate::ast::*;
+use crate::data::{Diagnostic, HasSrcPos, SrcPos, WithPos};
+use crate::syntax::TokenAccess;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Revisions of the VHDL language standard
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VHDLStandard {
+    #[default]
+    VHDL1993,
+    VHDL2008,
+}
+
+impl Display for VHDLStandard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VHDLStandard::VHDL1993 => write!(f, "VHDL-1993"),
+            VHDLStandard::VHDL2008 => write!(f, "VHDL-2008"),
+        }
+    }
+}
+
+impl FromStr for VHDLStandard {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().trim_start_matches("vhdl-") {
+            "93" | "1993" => Ok(VHDLStandard::VHDL1993),
+            "08" | "2008" => Ok(VHDLStandard::VHDL2008),
+            _ => Err(format!("'{s}' is not a supported VHDL standard")),
+        }
+    }
+}
+
+/// A language construct that requires a certain revision of the standard
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionedConstruct {
+    pub name: &'static str,
+    pub pos: SrcPos,
+    pub standard: VHDLStandard,
+}
+
+/// Find all constructs of a design file that require a standard newer than VHDL-1993
+pub fn find_versioned_constructs(design_file: &DesignFile) -> Vec<VersionedConstruct> {
+    let mut searcher = VersionedConstructSearcher::default();
+    for (tokens, unit) in design_file.design_units.iter() {
+        let _ = unit.search(tokens, &mut searcher);
+    }
+    searcher.constructs
+}
+
+/// The minimum standard that supports all constructs of a design file
+pub fn required_standard(design_file: &DesignFile) -> VHDLStandard {
+    find_versioned_constructs(design_file)
+        .into_iter()
+        .map(|construct| construct.standard)
+        .max()
+        .unwrap_or_default()
+}
+
+/// Report constructs of a design file that are newer than the configured standard
+pub(crate) fn check_standard(
+    design_file: &DesignFile,
+    configured: VHDLStandard,
+) -> Vec<Diagnostic> {
+    find_versioned_constructs(design_file)
+        .into_iter()
+        .filter(|construct| construct.standard > configured)
+        .map(|construct| {
+            Diagnostic::error(
+                &construct.pos,
+                format!(
+                    "'{}' requires {} but the configured standard is {configured}",
+                    construct.name, construct.standard
+                ),
+            )
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct VersionedConstructSearcher {
+    constructs: Vec<VersionedConstruct>,
+}
+
+impl VersionedConstructSearcher {
+    fn vhdl2008(&mut self, name: &'static str, pos: &SrcPos) {
+        self.constructs.push(VersionedConstruct {
+            name,
+            pos: pos.clone(),
+            standard: VHDLStandard::VHDL2008,
+        });
+    }
+
+    fn sequential_statement(&mut self, statement: &WithPos<SequentialStatement>) {
+        match statement.item {
+            SequentialStatement::Case(ref case_stmt) if case_stmt.is_matching => {
+                let pos = statement
+                    .pos
+                    .pos_at_beginning()
+                    .combine_into(&case_stmt.expression.pos);
+                self.vhdl2008("case?", &pos);
+            }
+            SequentialStatement::VariableAssignment(ref assign) => match assign.rhs {
+                AssignmentRightHand::Conditional(_) => {
+                    self.vhdl2008("conditional variable assignment", &statement.pos)
+                }
+                AssignmentRightHand::Selected(_) => {
+                    self.vhdl2008("selected variable assignment", &statement.pos)
+                }
+                AssignmentRightHand::Simple(_) => {}
+            },
+            SequentialStatement::SignalAssignment(ref assign) => match assign.rhs {
+                AssignmentRightHand::Conditional(_) => {
+                    self.vhdl2008("sequential conditional signal assignment", &statement.pos)
+                }
+                AssignmentRightHand::Selected(_) => {
+                    self.vhdl2008("sequential selected signal assignment", &statement.pos)
+                }
+                AssignmentRightHand::Simple(_) => {}
+            },
+            SequentialStatement::SignalForceAssignment(_) => {
+                self.vhdl2008("force assignment", &statement.pos)
+            }
+            SequentialStatement::SignalReleaseAssignment(_) => {
+                self.vhdl2008("release assignment", &statement.pos)
+            }
+            _ => {}
+        }
+    }
+
+    fn concurrent_statement(&mut self, statement: &WithPos<ConcurrentStatement>) {
+        match statement.item {
+            ConcurrentStatement::Process(ref process)
+                if matches!(process.sensitivity_list, Some(SensitivityList::All)) =>
+            {
+                self.vhdl2008("process(all)", &statement.pos)
+            }
+            ConcurrentStatement::CaseGenerate(_) => self.vhdl2008("case generate", &statement.pos),
+            _ => {}
+        }
+    }
+}
+
+impl Searcher for VersionedConstructSearcher {
+    fn search_decl(&mut self, _ctx: &dyn TokenAccess, decl: FoundDeclaration) -> SearchState {
+        match decl {
+            FoundDeclaration::Context(context) => {
+                self.vhdl2008("context declaration", context.ident.pos())
+            }
+            FoundDeclaration::InterfaceType(ident) => self.vhdl2008("generic type", ident.pos()),
+            FoundDeclaration::InterfacePackage(package) => {
+                self.vhdl2008("generic package", package.ident.pos())
+            }
+            FoundDeclaration::PackageInstance(instance) => {
+                self.vhdl2008("package instantiation", instance.ident.pos())
+            }
+            FoundDeclaration::SubprogramInstantiation(instance) => {
+                self.vhdl2008("subprogram instantiation", instance.ident.pos())
+            }
+            FoundDeclaration::SequentialStatement(statement) => {
+                self.sequential_statement(&statement.statement)
+            }
+            FoundDeclaration::ConcurrentStatement(statement) => {
+                self.concurrent_statement(&statement.statement)
+            }
+            _ => {}
+        }
+        NotFinished
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::test::Code;
+
+    #[test]
+    fn process_all_requires_vhdl2008() {
+        let code = Code::new(
+            "
+entity ent is
+end entity;
+
+architecture a of ent is
+    signal a, b : bit;
+begin
+    process(all)
+    begin
+        b <= a;
+    end process;
+end architecture;
+",
+        );
+
+        let design_file = code.design_file();
+        assert_eq!(required_standard(&design_file), VHDLStandard::VHDL2008);
+        assert_eq!(
+            find_versioned_constructs(&design_file)
+                .into_iter()
+                .map(|construct| construct.name)
+                .collect::<Vec<_>>(),
+            vec!["process(all)"]
+        );
+    }
+
+    #[test]
+    fn plain_file_requires_vhdl1993() {
+        let code = Code::new(
+            "
+entity ent is
+end entity;
+
+architecture a of ent is
+    signal a, b : bit;
+begin
+    process(a)
+    begin
+        case a is
+            when '0' => b <= '1';
+            when others => b <= '0';
+        end case;
+    end process;
+end architecture;
+",
+        );
+
+        assert_eq!(
+            required_standard(&code.design_file()),
+            VHDLStandard::VHDL1993
+        );
+    }
+
+    #[test]
+    fn parses_standard_names() {
+        assert_eq!("1993".parse(), Ok(VHDLStandard::VHDL1993));
+        assert_eq!("08".parse(), Ok(VHDLStandard::VHDL2008));
+        assert_eq!("VHDL-2008".parse(), Ok(VHDLStandard::VHDL2008));
+        assert!("2019".parse::<VHDLStandard>().is_err());
+    }
+}
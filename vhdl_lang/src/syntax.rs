@@ -16,6 +16,7 @@ mod configuration;
 mod context;
 mod declarative_part;
 mod design_unit;
+mod disconnection;
 mod expression;
 mod interface_declaration;
 mod names;
@@ -32,5 +33,6 @@ mod waveform;
 #[cfg(test)]
 pub mod test;
 
-pub use parser::{ParserResult, VHDLParser};
+pub(crate) use names::parse_name;
+pub use parser::{DesignUnit, ParserResult, VHDLParser};
 pub use tokens::*;
@@ -31,6 +31,11 @@ where
     Ok(optional)
 }
 
+/// VHDL-AMS constructs are recognized to give a clear error but are not analyzed
+pub fn ams_construct_error(pos: impl AsRef<SrcPos>) -> Diagnostic {
+    Diagnostic::error(pos, "VHDL-AMS construct not supported")
+}
+
 pub fn check_end_identifier_mismatch<T: std::fmt::Display + std::cmp::PartialEq>(
     ident: &WithPos<T>,
     end_ident: Option<WithPos<T>>,
@@ -4,6 +4,7 @@
 //
 // Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
 
+use super::common::ams_construct_error;
 use super::common::check_label_identifier_mismatch;
 use super::common::ParseResult;
 use super::declarative_part::{is_declarative_part, parse_declarative_part};
@@ -15,7 +16,7 @@ use super::names::{expression_to_ident, parse_association_list, parse_selected_n
 use super::range::parse_discrete_range;
 use super::sequential_statement::{
     parse_assert_statement, parse_labeled_sequential_statements, parse_selection,
-    parse_signal_assignment_right_hand, parse_target,
+    parse_sequential_statement, parse_signal_assignment_right_hand, parse_target,
 };
 use super::tokens::{Kind::*, TokenStream};
 use super::waveform::{parse_delay_mechanism, parse_waveform};
@@ -293,6 +294,15 @@ fn parse_assignment_or_procedure_call(
     stream: &TokenStream,
     target: WithPos<Target>,
 ) -> ParseResult<ConcurrentStatement> {
+    if stream.next_kinds_are(&[EQ, EQ]) {
+        // A VHDL-AMS simultaneous statement such as `a == b;`
+        let first = stream.peek_expect()?.pos.clone();
+        stream.skip();
+        return Err(ams_construct_error(
+            first.combine(&stream.peek_expect()?.pos),
+        ));
+    }
+
     expect_token!(stream, token,
     LTE => {
         parse_assignment_known_target(stream, target)
@@ -679,6 +689,23 @@ pub fn parse_labeled_concurrent_statements(
             End | Elsif | Else | When => {
                 break Ok(statements);
             }
+            _ if starts_sequential_only_statement(stream) => {
+                // Parse the misplaced statement to be able to continue after it
+                let pos = token.pos.clone();
+                match parse_sequential_statement(stream, diagnostics) {
+                    Ok(_) => diagnostics.error(
+                        pos,
+                        "Sequential statement is not allowed in a concurrent region",
+                    ),
+                    Err(diagnostic) => {
+                        diagnostics.push(diagnostic);
+                        stream.skip_until(|kind| {
+                            matches!(kind, SemiColon | End | Process | Block | Assert)
+                        })?;
+                        stream.pop_if_kind(SemiColon);
+                    }
+                }
+            }
             _ => match parse_labeled_concurrent_statement(stream, diagnostics) {
                 Ok(stmt) => {
                     statements.push(stmt);
@@ -695,6 +722,24 @@ pub fn parse_labeled_concurrent_statements(
     }
 }
 
+/// Statements that can only be sequential such as `wait` or an unlabeled `if`
+/// A labeled `if`, `case` or `for` is ambiguous with a generate statement until later
+fn starts_sequential_only_statement(stream: &TokenStream) -> bool {
+    const SEQUENTIAL_ONLY: [Kind; 8] = [Wait, Loop, While, Next, Exit, Return, Null, Report];
+
+    if stream.next_kinds_are(&[Identifier, Colon]) {
+        SEQUENTIAL_ONLY
+            .iter()
+            .any(|kind| stream.nth_kind_is(2, *kind))
+    } else {
+        stream.next_kind_is(If)
+            || stream.next_kind_is(Case)
+            || SEQUENTIAL_ONLY
+                .iter()
+                .any(|kind| stream.next_kind_is(*kind))
+    }
+}
+
 pub fn parse_labeled_concurrent_statement(
     stream: &TokenStream,
     diagnostics: &mut dyn DiagnosticHandler,
@@ -2067,4 +2112,99 @@ end arch;
             ],
         )
     }
+
+    #[test]
+    fn sequential_statement_in_concurrent_region() {
+        let code = Code::new(
+            "\
+architecture arch of ent is
+begin
+if cond then
+  a <= b;
+end if;
+lbl: wait;
+b <= c;
+end arch;
+        ",
+        );
+        let (arch, diag) = code.with_stream_diagnostics(parse_architecture_body);
+        check_diagnostics(
+            diag,
+            vec![
+                Diagnostic::error(
+                    code.s1("if"),
+                    "Sequential statement is not allowed in a concurrent region",
+                ),
+                Diagnostic::error(
+                    code.s1("lbl"),
+                    "Sequential statement is not allowed in a concurrent region",
+                ),
+            ],
+        );
+        // Parsing continues after the misplaced statements
+        assert_eq!(arch.statements.len(), 1);
+    }
+
+    #[test]
+    fn simultaneous_statement_is_not_supported() {
+        let code = Code::new(
+            "\
+architecture arch of ent is
+begin
+v == i * r;
+lbl: q'dot == 0.0;
+b <= c;
+end arch;
+        ",
+        );
+        let (arch, diag) = code.with_stream_diagnostics(parse_architecture_body);
+        check_diagnostics(
+            diag,
+            vec![
+                Diagnostic::error(code.s("==", 1), "VHDL-AMS construct not supported"),
+                Diagnostic::error(code.s("==", 2), "VHDL-AMS construct not supported"),
+            ],
+        );
+        // Parsing continues after the simultaneous statements
+        assert_eq!(arch.statements.len(), 1);
+    }
+
+    #[test]
+    fn concurrent_statement_in_sequential_region() {
+        let code = Code::new(
+            "\
+architecture arch of ent is
+begin
+process
+begin
+  inst: entity work.foo;
+  a <= b after 1 ns;
+  blk: block
+  begin
+  end block;
+  wait;
+end process;
+end arch;
+        ",
+        );
+        let (arch, diag) = code.with_stream_diagnostics(parse_architecture_body);
+        check_diagnostics(
+            diag,
+            vec![
+                Diagnostic::error(
+                    code.s1("inst"),
+                    "Concurrent statement is not allowed in a sequential region",
+                ),
+                Diagnostic::error(
+                    code.s1("blk"),
+                    "Concurrent statement is not allowed in a sequential region",
+                ),
+            ],
+        );
+
+        match &arch.statements[0].statement.item {
+            ConcurrentStatement::Process(process) => assert_eq!(process.statements.len(), 2),
+            stmt => panic!("Expected process, got {stmt:?}"),
+        }
+    }
 }
@@ -6,10 +6,11 @@
 
 use super::alias_declaration::parse_alias_declaration;
 use super::attributes::parse_attribute;
-use super::common::ParseResult;
+use super::common::{ams_construct_error, ParseResult};
 use super::component_declaration::parse_component_declaration;
 use super::configuration::parse_configuration_specification;
 use super::context::parse_use_clause;
+use super::disconnection::parse_disconnection_specification;
 use super::names::parse_selected_name;
 use super::object_declaration::{parse_file_declaration, parse_object_declaration};
 use super::subprogram::parse_subprogram;
@@ -47,13 +48,15 @@ pub fn is_declarative_part(stream: &TokenStream, begin_is_end: bool) -> ParseRes
 fn check_declarative_part(token: &Token, may_end: bool, may_begin: bool) -> ParseResult<()> {
     match token.kind {
         Use | Type | Subtype | Shared | Constant | Signal | Variable | File | Component
-        | Attribute | Alias | Impure | Pure | Function | Procedure | Package | For => Ok(()),
+        | Attribute | Alias | Impure | Pure | Function | Procedure | Package | For | Disconnect => {
+            Ok(())
+        }
         Begin if may_begin => Ok(()),
         End if may_end => Ok(()),
         _ => {
             let decl_kinds = [
                 Use, Type, Subtype, Shared, Constant, Signal, Variable, File, Component, Attribute,
-                Alias, Impure, Pure, Function, Procedure, Package, For,
+                Alias, Impure, Pure, Function, Procedure, Package, For, Disconnect,
             ];
 
             Err(token.kinds_error(&decl_kinds))
@@ -61,6 +64,40 @@ fn check_declarative_part(token: &Token, may_end: bool, may_begin: bool) -> Pars
     }
 }
 
+/// A label followed by a declaration such as `lbl: signal s : bit;`
+fn is_labeled_declaration(stream: &TokenStream) -> bool {
+    if !stream.next_kinds_are(&[Identifier, Colon]) {
+        return false;
+    }
+
+    let state = stream.state();
+    stream.skip();
+    stream.skip();
+    let is_declaration = stream
+        .peek()
+        .map(|token| check_declarative_part(token, false, false).is_ok())
+        .unwrap_or(false);
+    stream.set_state(state);
+    is_declaration
+}
+
+/// VHDL-AMS declarations such as `quantity q : real;` start with a word that is reserved
+/// in VHDL-AMS but is an identifier in plain VHDL
+fn is_ams_declaration(stream: &TokenStream) -> bool {
+    if !stream.next_kinds_are(&[Identifier, Identifier]) {
+        return false;
+    }
+
+    stream
+        .peek()
+        .and_then(|token| token.to_identifier_value().ok())
+        .map(|ident| {
+            let name = ident.item.name_utf8();
+            name.eq_ignore_ascii_case("quantity") || name.eq_ignore_ascii_case("terminal")
+        })
+        .unwrap_or(false)
+}
+
 pub fn parse_declarative_part(
     stream: &TokenStream,
     diagnostics: &mut dyn DiagnosticHandler,
@@ -86,6 +123,7 @@ pub fn parse_declarative_part(
                 | Attribute
                 | Use
                 | Alias
+                | Disconnect
                 | Begin
                 | End
         )
@@ -130,10 +168,12 @@ pub fn parse_declarative_part(
                 }
             }
 
-            Use | Alias => {
+            Use | Alias | Disconnect => {
                 let decl: ParseResult<Declaration> = match token.kind {
                     Use => parse_use_clause(stream, diagnostics).map(Declaration::Use),
                     Alias => parse_alias_declaration(stream).map(Declaration::Alias),
+                    Disconnect => parse_disconnection_specification(stream, diagnostics)
+                        .map(Declaration::Disconnection),
                     _ => unreachable!(),
                 };
                 match decl.or_recover_until(stream, diagnostics, is_recover_token) {
@@ -145,10 +185,23 @@ pub fn parse_declarative_part(
                 }
             }
 
+            Identifier if is_ams_declaration(stream) => {
+                diagnostics.push(ams_construct_error(&token.pos));
+                stream.skip_until(|kind| kind == SemiColon)?;
+                stream.skip();
+            }
+
+            Identifier if is_labeled_declaration(stream) => {
+                diagnostics.error(&token.pos, "Labels are not allowed on declarations");
+                // Skip the label and colon and parse the declaration as usual
+                stream.skip();
+                stream.skip();
+            }
+
             _ => {
                 diagnostics.push(token.kinds_error(&[
                     Type, Subtype, Component, Impure, Pure, Function, Procedure, Package, For,
-                    File, Shared, Constant, Signal, Variable, Attribute, Use, Alias,
+                    File, Shared, Constant, Signal, Variable, Attribute, Use, Alias, Disconnect,
                 ]));
                 stream.skip_until(is_recover_token)?;
                 continue;
@@ -239,7 +292,93 @@ constant x: natural := 5;
                 "Expected 'type', 'subtype', 'component', 'impure', 'pure', \
                  'function', 'procedure', 'package', 'for', 'file', \
                  'shared', 'constant', 'signal', 'variable', 'attribute', \
-                 'use' or 'alias'"
+                 'use', 'alias' or 'disconnect'"
+            )]
+        );
+    }
+
+    #[test]
+    fn quantity_declaration_is_not_supported() {
+        let code = Code::new(
+            "\
+quantity v across i through p to n;
+constant x: natural := 5;
+",
+        );
+        let (decls, msgs) = code.with_partial_stream_diagnostics(parse_declarative_part);
+        assert_eq!(
+            decls,
+            Ok(vec![Declaration::Object(ObjectDeclaration {
+                span: code.s1_to_end("constant").token_span(),
+                class: ObjectClass::Constant,
+                ident: code.s1("x").decl_ident(),
+                subtype_indication: code.s1("natural").subtype_indication(),
+                expression: Some(code.s1("5").expr())
+            })])
+        );
+
+        assert_eq!(
+            msgs,
+            vec![Diagnostic::error(
+                code.s1("quantity"),
+                "VHDL-AMS construct not supported"
+            )]
+        );
+    }
+
+    #[test]
+    fn parse_declarative_part_recover_from_missing_subtype() {
+        let code = Code::new(
+            "\
+signal s := 0;
+constant x: natural := 5;
+",
+        );
+        let (decls, msgs) = code.with_partial_stream_diagnostics(parse_declarative_part);
+        assert_eq!(
+            decls,
+            Ok(vec![Declaration::Object(ObjectDeclaration {
+                span: code.s1_to_end("constant").token_span(),
+                class: ObjectClass::Constant,
+                ident: code.s1("x").decl_ident(),
+                subtype_indication: code.s1("natural").subtype_indication(),
+                expression: Some(code.s1("5").expr())
+            })])
+        );
+
+        assert_eq!(
+            msgs,
+            vec![Diagnostic::error(
+                code.s1(":="),
+                "Expected subtype indication"
+            )]
+        );
+    }
+
+    #[test]
+    fn parse_declarative_part_labeled_declaration() {
+        let code = Code::new(
+            "\
+lbl: constant x: natural := 5;
+",
+        );
+        let (decls, msgs) = code.with_partial_stream_diagnostics(parse_declarative_part);
+        assert_eq!(
+            decls,
+            Ok(vec![Declaration::Object(ObjectDeclaration {
+                span: code.s1_to_end("constant").token_span(),
+                class: ObjectClass::Constant,
+                ident: code.s1("x").decl_ident(),
+                subtype_indication: code.s1("natural").subtype_indication(),
+                expression: Some(code.s1("5").expr())
+            })])
+        );
+
+        assert_eq!(
+            msgs,
+            vec![Diagnostic::error(
+                code.s1("lbl"),
+                "Labels are not allowed on declarations"
             )]
         );
     }
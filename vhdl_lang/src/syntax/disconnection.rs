@@ -0,0 +1,88 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::common::ParseResult;
+use super::expression::parse_expression;
+use super::names::parse_type_mark;
+use super::separated_list::parse_name_list;
+use super::tokens::{Kind::*, TokenSpan, TokenStream};
+use crate::ast::{DisconnectionSpecification, GuardedSignalList};
+use crate::data::DiagnosticHandler;
+
+/// LRM 7.4 Disconnection specification
+pub fn parse_disconnection_specification(
+    stream: &TokenStream,
+    diagnostics: &mut dyn DiagnosticHandler,
+) -> ParseResult<DisconnectionSpecification> {
+    let start_token = stream.expect_kind(Disconnect)?;
+    let signal_list = if stream.skip_if_kind(Others) {
+        GuardedSignalList::Others
+    } else if stream.skip_if_kind(All) {
+        GuardedSignalList::All
+    } else {
+        GuardedSignalList::Names(parse_name_list(stream, diagnostics)?)
+    };
+    stream.expect_kind(Colon)?;
+    let type_mark = parse_type_mark(stream)?;
+    stream.expect_kind(After)?;
+    let after = parse_expression(stream)?;
+    let end_token = stream.expect_kind(SemiColon)?;
+
+    Ok(DisconnectionSpecification {
+        span: TokenSpan::new(start_token, end_token),
+        signal_list,
+        type_mark,
+        after,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::test::Code;
+
+    #[test]
+    fn parse_disconnection_specification_with_names() {
+        let code = Code::new("disconnect sig1, sig2 : std_logic after 10 ns;");
+        assert_eq!(
+            code.with_stream_no_diagnostics(parse_disconnection_specification),
+            DisconnectionSpecification {
+                span: code.token_span(),
+                signal_list: GuardedSignalList::Names(code.s1("sig1, sig2").name_list()),
+                type_mark: code.s1("std_logic").type_mark(),
+                after: code.s1("10 ns").expr(),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_disconnection_specification_with_others() {
+        let code = Code::new("disconnect others : bit after 0 ns;");
+        assert_eq!(
+            code.with_stream_no_diagnostics(parse_disconnection_specification),
+            DisconnectionSpecification {
+                span: code.token_span(),
+                signal_list: GuardedSignalList::Others,
+                type_mark: code.s1("bit").type_mark(),
+                after: code.s1("0 ns").expr(),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_disconnection_specification_with_all() {
+        let code = Code::new("disconnect all : bit after delay;");
+        assert_eq!(
+            code.with_stream_no_diagnostics(parse_disconnection_specification),
+            DisconnectionSpecification {
+                span: code.token_span(),
+                signal_list: GuardedSignalList::All,
+                type_mark: code.s1("bit").type_mark(),
+                after: code.s1("delay").expr(),
+            }
+        );
+    }
+}
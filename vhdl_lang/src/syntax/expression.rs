@@ -193,12 +193,18 @@ pub fn parse_aggregate_initial_choices(
 }
 
 pub fn parse_aggregate(stream: &TokenStream) -> ParseResult<WithPos<Vec<ElementAssociation>>> {
-    stream.expect_kind(LeftPar)?;
+    let start = stream.expect_kind(LeftPar)?;
+    let start_pos = stream.get_pos(start).clone();
     if let Some(token) = stream.pop_if_kind(RightPar) {
-        return Ok(WithPos::from(Vec::new(), stream.get_pos(token).clone()));
+        return Ok(WithPos::from(
+            Vec::new(),
+            start_pos.combine(stream.get_pos(token)),
+        ));
     };
     let choices = parse_choices(stream)?;
-    parse_aggregate_initial_choices(stream, choices)
+    let aggregate = parse_aggregate_initial_choices(stream, choices)?;
+    let pos = start_pos.combine(&aggregate.pos);
+    Ok(WithPos::from(aggregate.item, pos))
 }
 
 fn parse_half_range(
@@ -402,7 +408,7 @@ fn parse_primary(stream: &TokenStream) -> ParseResult<WithPos<Expression>> {
             stream.skip();
             Ok(token
                 .to_character_value()?
-                .map_into(|chr| Expression::Literal(Literal::Character(chr))))
+                .map_into(|chr| Expression::Literal(Literal::Character(WithRef::new(chr)))))
         }
         StringLiteral => {
             if stream.next_kinds_are(&[StringLiteral, LeftPar]) {
@@ -548,7 +554,7 @@ mod tests {
         assert_eq!(
             code.with_stream(parse_expression),
             WithPos {
-                item: Expression::Literal(Literal::Character(b'a')),
+                item: Expression::Literal(Literal::Character(WithRef::new(b'a'))),
                 pos: code.pos()
             }
         );
@@ -1241,7 +1247,7 @@ mod tests {
                     AbstractLiteral::Integer(val) => format!("Integer({val})"),
                     AbstractLiteral::Real(val) => format!("Real({val})"),
                 },
-                Literal::Character(val) => format!("'{}'", Latin1String::new(&[*val])),
+                Literal::Character(val) => format!("'{}'", Latin1String::new(&[val.item])),
                 Literal::Physical(ref physical) => match physical.value {
                     AbstractLiteral::Integer(val) => {
                         format!("Physical(Integer({}), {})", val, physical.unit.item.name())
@@ -133,7 +133,7 @@ fn expression_to_name(expr: WithPos<Expression>) -> ParseResult<WithPos<Name>> {
             }
         }
         Expression::Literal(Literal::Character(val)) => Ok(WithPos {
-            item: Name::Designator(Designator::Character(val).into_ref()),
+            item: Name::Designator(Designator::Character(val.item).into_ref()),
             pos: expr.pos,
         }),
         _ => Err(Diagnostic::error(&expr, "Expected name")),
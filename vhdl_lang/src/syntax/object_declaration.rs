@@ -45,7 +45,16 @@ fn parse_object_declaration_kind(
     }
 
     let idents = parse_identifier_list(stream)?;
-    stream.expect_kind(Colon)?;
+    // Give a targeted error for a missing subtype such as `signal s := 0;`
+    if !stream.next_kind_is(ColonEq) {
+        stream.expect_kind(Colon)?;
+    }
+    if stream.next_kind_is(ColonEq) || stream.next_kind_is(SemiColon) {
+        return Err(Diagnostic::error(
+            stream.peek_expect()?,
+            "Expected subtype indication",
+        ));
+    }
     let subtype = parse_subtype_indication(stream)?;
     let opt_expression = parse_optional_assignment(stream)?;
     let end_token = stream.expect_kind(SemiColon)?;
@@ -247,6 +256,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn missing_subtype_indication() {
+        let code = Code::new("signal s := 0;");
+        assert_eq!(
+            code.with_partial_stream(parse_object_declaration),
+            Err(Diagnostic::error(
+                code.s1(":="),
+                "Expected subtype indication"
+            ))
+        );
+
+        let code = Code::new("constant c : ;");
+        assert_eq!(
+            code.with_partial_stream(parse_object_declaration),
+            Err(Diagnostic::error(
+                code.s1(";"),
+                "Expected subtype indication"
+            ))
+        );
+    }
+
     #[test]
     fn parses_optional_expression() {
         let code = Code::new("constant foo : natural := 0;");
@@ -5,8 +5,8 @@
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 
 use super::design_unit::parse_design_file;
-use super::tokens::{Symbols, TokenStream, Tokenizer};
-use crate::ast::DesignFile;
+use super::tokens::{Kind, Lexer, Symbols, Token, TokenStream, Tokenizer};
+use crate::ast::{AnyDesignUnit, DesignFile};
 use crate::data::*;
 use std::io;
 use std::sync::Arc;
@@ -18,6 +18,14 @@ pub struct VHDLParser {
 
 pub type ParserResult = Result<(Source, DesignFile), io::Error>;
 
+/// A top-level design unit of a source
+pub struct DesignUnit {
+    pub unit: AnyDesignUnit,
+    pub tokens: Vec<Token>,
+    /// The span of the unit including its context clause
+    pub pos: SrcPos,
+}
+
 impl VHDLParser {
     pub fn symbol(&self, name: &Latin1String) -> Symbol {
         self.symbols.symtab().insert(name)
@@ -41,6 +49,94 @@ impl VHDLParser {
         }
     }
 
+    /// Parse all top-level design units of a source in the order they appear.
+    /// Fails with the first error found while parsing.
+    pub fn design_units(&self, source: &Source) -> DiagnosticResult<Vec<DesignUnit>> {
+        let mut diagnostics = Vec::new();
+        let design_file = self.parse_design_source(source, &mut diagnostics);
+
+        if let Some(error) = diagnostics
+            .into_iter()
+            .find(|diagnostic| diagnostic.severity == Severity::Error)
+        {
+            return Err(error);
+        }
+
+        Ok(design_file
+            .design_units
+            .into_iter()
+            .filter_map(|(tokens, unit)| {
+                let pos = tokens.first()?.pos.combine(&tokens.last()?.pos);
+                Some(DesignUnit { unit, tokens, pos })
+            })
+            .collect())
+    }
+
+    /// Lazily tokenize the contents of a source without parsing it.
+    /// The contents are typically obtained from `source.contents()`.
+    pub fn lexer<'a>(&'a self, source: &'a Source, contents: &'a Contents) -> Lexer<'a> {
+        Lexer::new(Tokenizer::new(
+            &self.symbols,
+            source,
+            ContentReader::new(contents),
+        ))
+    }
+
+    /// Tokenize a source without parsing it.
+    /// Returns the kind and position of every token in order.
+    pub fn tokenize_source(
+        &self,
+        source: &Source,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> Vec<(Kind, SrcPos)> {
+        let contents = source.contents();
+        let tokenizer = Tokenizer::new(&self.symbols, source, ContentReader::new(&contents));
+        let stream = TokenStream::new(tokenizer, diagnostics);
+        stream
+            .iter_pos()
+            .map(|(kind, pos)| (kind, pos.clone()))
+            .collect()
+    }
+
+    /// The code of a source with all line and block comments removed.
+    /// Line breaks are kept, also those within block comments, so that the line count
+    /// does not change. Characters outside of Latin-1 that are not within a comment
+    /// are lexical errors and are replaced by '?'.
+    pub fn strip_comments(&self, source: &Source) -> Latin1String {
+        let contents = source.contents();
+        let mut lexer = self.lexer(source, &contents);
+
+        let mut comments = Vec::new();
+        for token in lexer.by_ref().flatten() {
+            if let Some(token_comments) = token.comments {
+                comments.extend(token_comments.leading.into_iter().map(|c| c.range));
+                comments.extend(token_comments.trailing.into_iter().map(|c| c.range));
+            }
+        }
+        if let Some(final_comments) = lexer.into_tokenizer().get_final_comments() {
+            comments.extend(final_comments.into_iter().map(|c| c.range));
+        }
+        comments.sort_by_key(|range| range.start);
+
+        let mut bytes = Vec::new();
+        let mut comments = comments.into_iter().peekable();
+        for lineno in 0..contents.num_lines() {
+            let Some(line) = contents.get_line(lineno) else {
+                continue;
+            };
+            let mut pos = Position::new(lineno as u32, 0);
+            for chr in line.chars() {
+                while comments.next_if(|range| range.end <= pos).is_some() {}
+                let in_comment = comments.peek().is_some_and(|range| range.start <= pos);
+                if chr == '\n' || !in_comment {
+                    bytes.push(char_to_latin1(chr).unwrap_or(b'?'));
+                }
+                pos.move_after_char(chr);
+            }
+        }
+        Latin1String::from_vec(bytes)
+    }
+
     pub fn parse_design_file(
         &self,
         file_name: &Path,
@@ -51,3 +147,134 @@ impl VHDLParser {
         Ok((source, design_file))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::AnySecondaryUnit;
+    use crate::syntax::test::Code;
+
+    #[test]
+    fn tokenize_source_skips_tool_directives() {
+        let code = Code::new("`protect begin\na <= b;");
+        let parser = VHDLParser {
+            symbols: code.symbols.clone(),
+        };
+
+        let mut diagnostics = Vec::new();
+        assert_eq!(
+            parser.tokenize_source(code.source(), &mut diagnostics),
+            vec![
+                (Kind::Identifier, code.s1("a").pos()),
+                (Kind::LTE, code.s1("<=").pos()),
+                (Kind::Identifier, code.s1("b;").s1("b").pos()),
+                (Kind::SemiColon, code.s1(";").pos()),
+            ]
+        );
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn design_units_of_source() {
+        let code = Code::new(
+            "
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+begin
+end architecture;
+",
+        );
+        let parser = VHDLParser {
+            symbols: code.symbols.clone(),
+        };
+
+        let units = parser.design_units(code.source()).unwrap();
+        assert_eq!(units.len(), 2);
+        assert!(units[0].unit.is_entity());
+        assert_eq!(
+            units[0].pos,
+            code.s1("library")
+                .pos()
+                .combine(&code.s1("end entity;").pos())
+        );
+        assert!(matches!(
+            units[1].unit,
+            AnyDesignUnit::Secondary(AnySecondaryUnit::Architecture(_))
+        ));
+        assert_eq!(
+            units[1].pos,
+            code.s1("architecture")
+                .pos()
+                .combine(&code.s1("end architecture;").pos())
+        );
+    }
+
+    #[test]
+    fn design_units_fails_on_parse_error() {
+        let code = Code::new(
+            "
+entity ent is
+end entity;
+
+architecture a of ent
+",
+        );
+        let parser = VHDLParser {
+            symbols: code.symbols.clone(),
+        };
+
+        assert!(parser.design_units(code.source()).is_err());
+    }
+
+    #[test]
+    fn strip_line_comments() {
+        let code = Code::new(
+            "\
+-- header
+entity ent is -- trailing
+  generic (g : natural := 1); -- åäö €
+end entity;
+",
+        );
+        let parser = VHDLParser {
+            symbols: code.symbols.clone(),
+        };
+
+        let stripped = parser.strip_comments(code.source());
+        assert_eq!(
+            stripped,
+            Latin1String::from_utf8_unchecked(
+                "\
+\nentity ent is \n  generic (g : natural := 1); \nend entity;\n"
+            )
+        );
+    }
+
+    #[test]
+    fn strip_block_comments_keeps_lines() {
+        let code = Code::new(
+            "\
+entity ent /* is
+a block */ is
+end entity; /* last */ -- line",
+        );
+        let parser = VHDLParser {
+            symbols: code.symbols.clone(),
+        };
+
+        let stripped = parser.strip_comments(code.source());
+        assert_eq!(
+            stripped,
+            Latin1String::from_utf8_unchecked("entity ent \n is\nend entity;  ")
+        );
+        assert_eq!(
+            stripped.to_string().lines().count(),
+            code.source().contents().num_lines()
+        );
+    }
+}
@@ -102,10 +102,12 @@ pub fn parse_ident_list(
 
 #[cfg(test)]
 mod test {
+    use crate::ast::WithRef;
     use crate::ast::{IdentList, NameList, SeparatedList};
     use crate::syntax::names::parse_association_element;
     use crate::syntax::separated_list::{
-        parse_ident_list, parse_list_with_separator_or_recover, parse_name_list,
+        parse_ident_list, parse_list_with_separator, parse_list_with_separator_or_recover,
+        parse_name_list,
     };
     use crate::syntax::test::Code;
     use crate::syntax::Kind;
@@ -232,6 +234,56 @@ mod test {
         );
     }
 
+    #[test]
+    fn parse_list_with_empty_element() {
+        let code = Code::new("abc, , ghi");
+        let (res, diag) = code.with_stream_diagnostics(parse_ident_list);
+        assert_eq!(
+            res,
+            IdentList {
+                items: vec![
+                    code.s1("abc").ident().into_ref(),
+                    code.s1("ghi").ident().into_ref()
+                ],
+                tokens: vec![code.s1(",").token()]
+            }
+        );
+        assert_eq!(
+            diag,
+            vec![Diagnostic::error(code.s(",", 2).pos(), "Extraneous ','")]
+        )
+    }
+
+    #[test]
+    fn parse_recoverable_list_continues_after_bad_element() {
+        let code = Code::new("abc, 1 2, ghi)");
+        let (res, diag) = code.with_stream_diagnostics(|stream, diag| {
+            let res = parse_list_with_separator_or_recover(
+                stream,
+                Kind::Comma,
+                diag,
+                |stream| stream.expect_ident().map(WithRef::new),
+                Some(RightPar),
+            );
+            stream.skip();
+            res
+        });
+        assert_eq!(
+            res,
+            IdentList {
+                items: vec![
+                    code.s1("abc").ident().into_ref(),
+                    code.s1("ghi").ident().into_ref()
+                ],
+                tokens: vec![code.s(",", 1).token(), code.s(",", 2).token()]
+            }
+        );
+        assert_eq!(
+            diag,
+            vec![Diagnostic::error(code.s1("1"), "Expected '{identifier}'")]
+        );
+    }
+
     #[test]
     fn parse_list_with_erroneous_elements() {
         let code = Code::new("1,c,d");
@@ -244,4 +296,102 @@ mod test {
             Diagnostic::error(code.s1("1"), "Expected '{identifier}'")
         );
     }
+
+    #[test]
+    fn trailing_separator_disallowed() {
+        let code = Code::new("a, b,");
+        let (res, diag) = code.with_partial_stream_diagnostics(parse_ident_list);
+        assert_eq!(
+            res,
+            Err(Diagnostic::error(code.eof_pos(), "Unexpected EOF"))
+        );
+        assert!(diag.is_empty());
+    }
+
+    #[test]
+    fn malformed_last_element_is_not_a_trailing_separator() {
+        let code = Code::new("a, b, 1");
+        let (res, diag) = code.with_partial_stream_diagnostics(parse_ident_list);
+        assert_eq!(
+            res,
+            Err(Diagnostic::error(code.s1("1"), "Expected '{identifier}'"))
+        );
+        assert!(diag.is_empty());
+    }
+
+    #[test]
+    fn parse_semicolon_separated_list() {
+        let code = Code::new("a; b;; c");
+        let (res, diag) = code.with_stream_diagnostics(|stream, diagnostics| {
+            parse_list_with_separator(stream, Kind::SemiColon, diagnostics, |stream| {
+                stream.expect_ident().map(WithRef::new)
+            })
+        });
+        assert_eq!(
+            res,
+            IdentList {
+                items: vec![
+                    code.s1("a").ident().into_ref(),
+                    code.s1("b").ident().into_ref(),
+                    code.s1("c").ident().into_ref()
+                ],
+                tokens: vec![code.s(";", 1).token(), code.s(";", 2).token()]
+            }
+        );
+        assert_eq!(
+            diag,
+            vec![Diagnostic::error(code.s(";", 3).pos(), "Extraneous ';'")]
+        );
+    }
+
+    #[test]
+    fn leading_comments_of_elements() {
+        let code = Code::new(
+            "\
+abc, -- after abc
+  -- before def
+  def,
+  /* before ghi */ ghi",
+        );
+        let list = code.parse_ok_no_diagnostics(parse_ident_list);
+        let tokens = code.tokenize();
+        let comments = |index: usize| -> Vec<String> {
+            list.leading_comments(index, &tokens)
+                .into_iter()
+                .map(|comment| comment.value.clone())
+                .collect()
+        };
+
+        assert!(comments(0).is_empty());
+        assert_eq!(comments(1), vec![" after abc", " before def"]);
+        assert_eq!(comments(2), vec![" before ghi "]);
+        assert!(comments(3).is_empty());
+    }
+
+    #[test]
+    fn separated_list_accessors() {
+        let code = Code::new("abc, def");
+        let mut list = code.parse_ok_no_diagnostics(parse_ident_list);
+
+        assert_eq!(list.len(), 2);
+        assert!(!list.is_empty());
+        assert_eq!(list.separators(), &[code.s1(",").token()]);
+        assert_eq!(list.first(), Some(&code.s1("abc").ident().into_ref()));
+        assert_eq!(list.last(), Some(&code.s1("def").ident().into_ref()));
+        assert_eq!(
+            list.iter()
+                .map(|ident| ident.item.pos.clone())
+                .collect::<Vec<_>>(),
+            vec![code.s1("abc").pos(), code.s1("def").pos()]
+        );
+        assert_eq!(list.iter_mut().count(), 2);
+        assert_eq!(
+            list.into_iter().collect::<Vec<_>>(),
+            vec![
+                code.s1("abc").ident().into_ref(),
+                code.s1("def").ident().into_ref()
+            ]
+        );
+        assert!(IdentList::default().is_empty());
+    }
 }
@@ -6,6 +6,7 @@
 
 use super::common::parse_optional;
 use super::common::ParseResult;
+use super::concurrent_statement::parse_labeled_concurrent_statement;
 use super::expression::parse_aggregate;
 use super::expression::{parse_choices, parse_expression};
 use super::names::parse_name;
@@ -76,6 +77,20 @@ pub fn parse_labeled_sequential_statements(
             End | Else | Elsif | When => {
                 break Ok(statements);
             }
+            _ if starts_concurrent_only_statement(stream) => {
+                // Parse the misplaced statement to be able to continue after it
+                let pos = token.pos.clone();
+                match parse_labeled_concurrent_statement(stream, diagnostics) {
+                    Ok(_) => diagnostics.error(
+                        pos,
+                        "Concurrent statement is not allowed in a sequential region",
+                    ),
+                    Err(diag) => {
+                        diagnostics.push(diag);
+                        let _ = stream.skip_until(|kind| matches!(kind, End | Else | Elsif | When));
+                    }
+                }
+            }
             _ => match parse_sequential_statement(stream, diagnostics) {
                 Ok(stmt) => statements.push(stmt),
                 Err(diag) => {
@@ -87,6 +102,17 @@ pub fn parse_labeled_sequential_statements(
     }
 }
 
+/// Statements that can only be concurrent such as a process or an instantiation
+fn starts_concurrent_only_statement(stream: &TokenStream) -> bool {
+    if stream.next_kinds_are(&[Identifier, Colon]) {
+        [Process, Block, Entity, Component, Configuration, Postponed]
+            .iter()
+            .any(|kind| stream.nth_kind_is(2, *kind))
+    } else {
+        stream.next_kind_is(Process) || stream.next_kind_is(Block) || stream.next_kind_is(Postponed)
+    }
+}
+
 /// LRM 10.8 If statement
 fn parse_if_statement(
     stream: &TokenStream,
@@ -6,7 +6,9 @@
 
 #[macro_use]
 mod tokenizer;
+mod lexer;
 mod tokenstream;
 
+pub use lexer::*;
 pub use tokenizer::*;
 pub use tokenstream::*;
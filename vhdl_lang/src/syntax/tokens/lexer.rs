@@ -0,0 +1,139 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use std::collections::VecDeque;
+
+use super::tokenizer::Kind::*;
+use super::tokenizer::*;
+use crate::data::DiagnosticResult;
+use crate::Diagnostic;
+
+/// Lazily yields the tokens of a source, one at a time.
+/// Tool directives are skipped and lexical errors are yielded in place of tokens.
+pub struct Lexer<'a> {
+    tokenizer: Tokenizer<'a>,
+    // Errors found while skipping a tool directive
+    pending: VecDeque<Diagnostic>,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(tokenizer: Tokenizer<'a>) -> Lexer<'a> {
+        Lexer {
+            tokenizer,
+            pending: VecDeque::new(),
+        }
+    }
+
+    pub fn into_tokenizer(self) -> Tokenizer<'a> {
+        self.tokenizer
+    }
+
+    /// Special handling for a tool directive of the form
+    /// ```vhdl
+    /// `identifier { any chars until newline }
+    /// ```
+    /// This needs special handling as the text that follows the identifier is arbitrary.
+    fn handle_tool_directive(&mut self, grave_accent: Token) {
+        let start_pos = grave_accent.pos.clone();
+        match self.tokenizer.pop() {
+            Ok(Some(tok)) => {
+                if tok.kind != Identifier {
+                    self.pending
+                        .push_back(Diagnostic::error(tok, "Expecting identifier"));
+                    let _ = self.tokenizer.text_until_newline(); // skip potentially invalid tokens
+                    return;
+                }
+            }
+            Err(err) => self.pending.push_back(err),
+            Ok(None) => {
+                self.pending
+                    .push_back(Diagnostic::error(start_pos, "Expecting identifier"));
+                return;
+            }
+        }
+        match self.tokenizer.text_until_newline() {
+            Ok(_) => {}
+            Err(err) => self.pending.push_back(err),
+        }
+    }
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = DiagnosticResult<Token>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(err) = self.pending.pop_front() {
+                return Some(Err(err));
+            }
+
+            match self.tokenizer.pop() {
+                Ok(Some(token)) if token.kind == GraveAccent => self.handle_tool_directive(token),
+                Ok(Some(token)) => return Some(Ok(token)),
+                Ok(None) => return None,
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::ContentReader;
+    use crate::syntax::test::Code;
+
+    #[test]
+    fn lazy_tokens_equal_eager_tokens() {
+        let mut code = String::new();
+        for i in 0..2000 {
+            code.push_str(&format!(
+                "signal s{i} : std_logic_vector({i} downto 0) := x\"{i:X}\"; -- comment {i}\n"
+            ));
+        }
+        let code = Code::new(&code);
+        let source = code.source();
+        let contents = source.contents();
+
+        let eager = code.tokenize();
+
+        let tokenizer = Tokenizer::new(&code.symbols, source, ContentReader::new(&contents));
+        let mut lexer = Lexer::new(tokenizer);
+
+        // Tokens are produced on demand
+        let first = lexer.next().unwrap().unwrap();
+        assert_eq!(first.kind, Signal);
+        assert_eq!(first.pos, code.s1("signal").pos());
+
+        let mut num_tokens = 1;
+        for (token, expected) in lexer.by_ref().zip(eager.iter().skip(1)) {
+            assert_eq!(token.as_ref(), Ok(expected));
+            num_tokens += 1;
+        }
+        assert_eq!(num_tokens, eager.len());
+        assert!(lexer.next().is_none());
+    }
+
+    #[test]
+    fn skips_tool_directives_and_yields_errors() {
+        let code = Code::new("`protect begin\nfoo `123\nbar");
+        let source = code.source();
+        let contents = source.contents();
+        let tokenizer = Tokenizer::new(&code.symbols, source, ContentReader::new(&contents));
+
+        let items: Vec<_> = Lexer::new(tokenizer).collect();
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].as_ref().unwrap().pos, code.s1("foo").pos());
+        assert_eq!(
+            items[1],
+            Err(Diagnostic::error(
+                code.s1("123").pos(),
+                "Expecting identifier"
+            ))
+        );
+        assert_eq!(items[2].as_ref().unwrap().pos, code.s1("bar").pos());
+    }
+}
@@ -97,6 +97,7 @@ pub enum Kind {
     Vunit,
     Parameter,
     Literal,
+    Disconnect,
 
     // Unary operators
     Abs,
@@ -265,6 +266,13 @@ macro_rules! try_init_token_kind {
     }
 }
 
+impl Kind {
+    /// True for reserved words such as `entity` or `and`
+    pub fn is_reserved_word(self) -> bool {
+        kind_str(self).chars().all(|chr| chr.is_ascii_alphabetic())
+    }
+}
+
 pub fn kind_str(kind: Kind) -> &'static str {
     match kind {
         // Keywords
@@ -351,6 +359,7 @@ pub fn kind_str(kind: Kind) -> &'static str {
         Vunit => "vunit",
         Parameter => "parameter",
         Literal => "literal",
+        Disconnect => "disconnect",
 
         // Unary operators
         Abs => "abs",
@@ -480,6 +489,11 @@ impl TokenId {
         TokenId(idx)
     }
 
+    /// The ID of the token that follows this one
+    pub(crate) fn next(&self) -> TokenId {
+        TokenId(self.0 + 1)
+    }
+
     /// In unit tests this function can be used to simulate 'token slicing' as it is done by the
     /// method `TokenStream::slice_tokens`.
     #[cfg(test)]
@@ -1565,6 +1579,7 @@ impl std::default::Default for Symbols {
             ("rem", Rem),
             ("vunit", Vunit),
             ("parameter", Parameter),
+            ("disconnect", Disconnect),
         ];
 
         let attributes = [
@@ -1970,7 +1985,6 @@ impl<'a> Tokenizer<'a> {
         }
     }
 
-    #[allow(dead_code)]
     pub fn get_final_comments(&self) -> Option<Vec<Comment>> {
         self.final_comments.clone()
     }
@@ -2704,6 +2718,30 @@ comment
         );
     }
 
+    #[test]
+    fn tokenize_comment_markers_within_comments() {
+        // Block comments do not nest and line comment markers are not special within them
+        assert_eq!(
+            kinds_tokenize(
+                "
+1 /* -- /* comment */ -2
+3 -- /* comment
+-4 /* */ */
+"
+            ),
+            vec![
+                AbstractLiteral,
+                Minus,
+                AbstractLiteral,
+                AbstractLiteral,
+                Minus,
+                AbstractLiteral,
+                Times,
+                Div
+            ]
+        );
+    }
+
     #[test]
     fn tokenize_ir1045() {
         // http://www.eda-stds.org/isac/IRs-VHDL-93/IR1045.txt
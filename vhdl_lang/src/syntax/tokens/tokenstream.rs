@@ -6,6 +6,7 @@
 
 use std::cell::Cell;
 
+use super::lexer::Lexer;
 use super::tokenizer::Kind::*;
 use super::tokenizer::*;
 use crate::ast::{AttributeDesignator, Ident, RangeAttribute, TypeAttribute};
@@ -23,54 +24,20 @@ pub struct TokenStream<'a> {
 }
 
 impl<'a> TokenStream<'a> {
-    /// Special handling for a tool directive of the form
-    /// ```vhdl
-    /// `identifier { any chars until newline }
-    /// ```
-    /// This needs special handling as the text that follows the identifier is arbitrary.
-    fn handle_tool_directive(
-        grave_accent: Token,
-        tokenizer: &mut Tokenizer,
-        diagnostics: &mut dyn DiagnosticHandler,
-    ) {
-        let start_pos = grave_accent.pos.clone();
-        match tokenizer.pop() {
-            Ok(Some(tok)) => {
-                if tok.kind != Identifier {
-                    diagnostics.error(tok, "Expecting identifier");
-                    let _ = tokenizer.text_until_newline(); // skip potentially invalid tokens
-                    return;
-                }
-            }
-            Err(err) => diagnostics.push(err),
-            Ok(None) => {
-                diagnostics.error(start_pos, "Expecting identifier");
-                return;
-            }
-        }
-        match tokenizer.text_until_newline() {
-            Ok(_) => {}
-            Err(err) => diagnostics.push(err),
-        }
-    }
-
     pub fn new(
-        mut tokenizer: Tokenizer<'a>,
+        tokenizer: Tokenizer<'a>,
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> TokenStream<'a> {
+        let mut lexer = Lexer::new(tokenizer);
         let mut tokens = Vec::new();
-        loop {
-            match tokenizer.pop() {
-                Ok(Some(token)) if token.kind == GraveAccent => {
-                    TokenStream::handle_tool_directive(token, &mut tokenizer, diagnostics)
-                }
-                Ok(Some(token)) => tokens.push(token),
-                Ok(None) => break,
+        for token in lexer.by_ref() {
+            match token {
+                Ok(token) => tokens.push(token),
                 Err(err) => diagnostics.push(err),
             }
         }
         TokenStream {
-            tokenizer,
+            tokenizer: lexer.into_tokenizer(),
             idx: Cell::new(0),
             tokens,
             token_offset: Cell::new(0),
@@ -113,6 +80,12 @@ impl<'a> TokenStream<'a> {
         TokenId::new(self.get_idx() - 1 - self.token_offset.get())
     }
 
+    /// Iterate over the kind and position of every token in the stream, in order.
+    /// The iteration is independent of the current position of the stream.
+    pub fn iter_pos(&self) -> impl Iterator<Item = (Kind, &SrcPos)> {
+        self.tokens.iter().map(|token| (token.kind, &token.pos))
+    }
+
     pub fn last(&self) -> Option<&Token> {
         let last_idx = self.get_idx().checked_sub(1)?;
         self.tokens.get(last_idx)
@@ -384,6 +357,23 @@ mod tests {
         assert_eq!(stream.peek(), None);
     }
 
+    #[test]
+    fn iter_pos() {
+        let code = Code::new("a <= b;");
+        new_stream!(code, stream);
+        stream.skip();
+
+        assert_eq!(
+            stream.iter_pos().collect_vec(),
+            vec![
+                (Identifier, &code.s1("a").pos()),
+                (LTE, &code.s1("<=").pos()),
+                (Identifier, &code.s1("b").pos()),
+                (SemiColon, &code.s1(";").pos()),
+            ]
+        );
+    }
+
     #[test]
     fn idx_of() {
         let code = Code::new("hello world again");
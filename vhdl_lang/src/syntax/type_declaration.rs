@@ -21,6 +21,11 @@ use crate::syntax::names::parse_type_mark;
 /// LRM 5.2.2 Enumeration types
 fn parse_enumeration_type_definition(stream: &TokenStream) -> ParseResult<TypeDefinition> {
     let mut enum_literals = Vec::new();
+    // Empty enumerations are rejected during analysis
+    if stream.skip_if_kind(RightPar) {
+        return Ok(TypeDefinition::Enumeration(enum_literals));
+    }
+
     loop {
         expect_token!(stream,
             literal_token,
@@ -361,6 +366,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_empty_enumeration_type_definition() {
+        let code = Code::new("type foo is ();");
+
+        let type_decl = TypeDeclaration {
+            span: code.token_span(),
+            ident: code.s1("foo").decl_ident(),
+            def: TypeDefinition::Enumeration(vec![]),
+            end_ident_pos: None,
+        };
+        assert_eq!(
+            code.with_stream_no_diagnostics(parse_type_declaration),
+            type_decl
+        );
+    }
+
     #[test]
     fn mixing_identifier_and_scalar_in_enumerations() {
         let code = Code::new("type foo is (ident, 'b');");
@@ -116,8 +116,20 @@ impl VHDLServer {
     pub fn initialize_request(&mut self, init_params: InitializeParams) -> InitializeResult {
         self.config_file = self.root_uri_config_file(&init_params);
         let config = self.load_config();
+        let naming_rules = config.naming_rules().cloned();
+        let buffer_port_detection = config.buffer_port_detection();
+        let deprecated_rules = config.deprecated_rules().cloned();
         self.project = Project::from_config(config, &mut self.message_filter());
         self.project.enable_unused_declaration_detection();
+        if let Some(rules) = naming_rules {
+            self.project.enable_identifier_case_rules(rules);
+        }
+        if buffer_port_detection {
+            self.project.enable_buffer_port_detection();
+        }
+        if let Some(rules) = deprecated_rules {
+            self.project.enable_deprecated_construct_rules(rules);
+        }
         self.init_params = Some(init_params);
         let trigger_chars: Vec<String> = r".".chars().map(|ch| ch.to_string()).collect();
 